@@ -0,0 +1,25 @@
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+
+use rustykube_core::config::Config;
+use rustykube_core::fixtures::{self, FixtureSize};
+use rustykube_core::lint_rules;
+use rustykube_core::linter::Linter;
+
+fn lint(c: &mut Criterion) {
+    let config = Config::default();
+    let linter = Linter::new(lint_rules::default_rules(&config), config);
+
+    let mut group = c.benchmark_group("lint");
+    group.sample_size(10);
+    for size in FixtureSize::ALL {
+        let manifests = fixtures::manifests(size.apps());
+        group.throughput(Throughput::Elements(size.apps() as u64 * 5));
+        group.bench_with_input(BenchmarkId::from_parameter(size.name()), &manifests, |b, manifests| {
+            b.iter(|| linter.lint_str("bench.yaml", manifests))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, lint);
+criterion_main!(benches);
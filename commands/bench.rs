@@ -0,0 +1,71 @@
+use std::cmp::Reverse;
+use std::process;
+use std::time::{Duration, Instant};
+
+use rustykube_core::config::Config;
+use rustykube_core::fixtures::{self, FixtureSize};
+use rustykube_core::lint_rules;
+use rustykube_core::linter::Linter;
+
+/// Times linting a synthetic fixture set, overall and per rule, so rule
+/// changes can be compared without setting up criterion. Exits non-zero
+/// when a run takes more than `max_ms_per_doc` per document.
+pub fn run_bench(size: &str, iterations: u32, max_ms_per_doc: Option<f64>) {
+    let Some(size) = FixtureSize::parse(size) else {
+        eprintln!("❌ Unknown fixture size '{}'; expected small, medium or huge.", size);
+        process::exit(1);
+    };
+    let iterations = iterations.max(1);
+    let config = Config::default();
+    let manifests = fixtures::manifests(size.apps());
+    let documents = size.apps() * 5;
+
+    let linter = Linter::new(lint_rules::default_rules(&config), Config::default());
+    let total = time(iterations, || {
+        linter.lint_str("bench.yaml", &manifests);
+    });
+    println!("--- Benchmark: {} ({} documents, {} iterations) ---\n", size.name(), documents, iterations);
+    let ms_per_doc = total.as_secs_f64() * 1000.0 / documents as f64;
+    println!("All rules: {:.2?} per run, {:.0} documents/s, {:.3} ms/document\n", total, documents as f64 / total.as_secs_f64(), ms_per_doc);
+
+    // Parsing and source mapping happen whatever rules run; charge them
+    // once instead of to every rule.
+    let baseline = time(iterations, || {
+        Linter::new(vec![], Config::default()).lint_str("bench.yaml", &manifests);
+    });
+    println!("Parsing only: {:.2?} per run\n", baseline);
+
+    let rule_count = lint_rules::default_rules(&config).len();
+    let mut per_rule: Vec<(&'static str, Duration)> = (0..rule_count)
+        .map(|i| {
+            let rule = lint_rules::default_rules(&config).swap_remove(i);
+            let id = rule.id();
+            let linter = Linter::new(vec![rule], Config::default());
+            let duration = time(iterations, || {
+                linter.lint_str("bench.yaml", &manifests);
+            });
+            (id, duration.saturating_sub(baseline))
+        })
+        .collect();
+    per_rule.sort_by_key(|(_, duration)| Reverse(*duration));
+
+    println!("Slowest rules:");
+    for (id, duration) in per_rule.iter().take(10) {
+        println!("  {:<32} {:.2?}", id, duration);
+    }
+    println!();
+
+    if let Some(max) = max_ms_per_doc.filter(|max| ms_per_doc > *max) {
+        eprintln!("❌ Linting took {:.3} ms per document, over the {:.3} ms limit.", ms_per_doc, max);
+        process::exit(1);
+    }
+}
+
+/// Mean duration of `iterations` runs of `f`.
+fn time(iterations: u32, mut f: impl FnMut()) -> Duration {
+    let start = Instant::now();
+    for _ in 0..iterations {
+        f();
+    }
+    start.elapsed() / iterations
+}
@@ -1,85 +1,143 @@
-use std::fs;
-use crate::utils;
-use crate::lint_rules::{LintRule, LivenessProbeRule, MissingLabelsRule, ReadinessProbeRule, ResourceLimitsRule, RunAsNonRootRule, ReadOnlyRootFilesystemRule, LatestImageTagRule};
+use std::path::PathBuf;
+use std::process;
 
-pub fn run_lint(path: &str, json: bool) {
-    let contents = fs::read_to_string(path).expect("Failed to read file");
-    let docs = utils::parse_yaml(&contents);
+use rustykube_core::config::Config;
+use rustykube_core::lint_rules::{self, Severity};
+use rustykube_core::discovery::Discovery;
+use rustykube_core::linter::{Linter, Target};
 
-    let rules: Vec<Box<dyn LintRule>> = vec![
-        Box::new(MissingLabelsRule),
-        Box::new(ResourceLimitsRule),
-        Box::new(LivenessProbeRule),
-        Box::new(ReadinessProbeRule),
-        Box::new(RunAsNonRootRule),
-        Box::new(ReadOnlyRootFilesystemRule),
-        Box::new(LatestImageTagRule)
-    ];
+use super::output::Output;
+use super::style::Style;
 
-    let mut results = vec![];
-    let mut total_issues = 0;
+/// Exit status when findings at or above the `--fail-on` severity were
+/// reported.
+pub const EXIT_FINDINGS: i32 = 1;
+/// Exit status for bad config, unreadable inputs and failed writes.
+pub const EXIT_ERROR: i32 = 2;
 
-    println!("\n--- Linting Results ---\n");
+/// Where results go and which findings fail the run.
+pub struct ReportOptions {
+    pub outputs: Vec<Output>,
+    /// Findings of this severity or worse make lint exit with
+    /// `EXIT_FINDINGS`.
+    pub fail_on: Severity,
+}
 
-    for (i, doc) in docs.iter().enumerate() {
-    
-        let resource_kind = doc
-        .get("kind")
-        .and_then(|v| v.as_str())
-        .unwrap_or("Unknown type");
-    
-        let resource_name = doc
-            .get("metadata")
-            .and_then(|metadata| metadata.get("name"))
-            .and_then(|name| name.as_str())
-            .unwrap_or("Unnamed resource");
-        
-        println!("📄 Resource {}, of Type: {}:", resource_name, resource_kind);
-    
-        let mut resource_issues = vec![];
+/// How files are read before linting.
+pub struct FileOptions {
+    /// Files larger than this many bytes are skipped.
+    pub max_file_size: Option<u64>,
+    /// Skip files without Kubernetes markers before parsing them.
+    pub prescan: bool,
+    /// Lint documents that do not look like Kubernetes resources.
+    pub include_non_kubernetes: bool,
+    /// Only lint documents matching one of these; all when empty.
+    pub targets: Vec<Target>,
+}
 
-        for rule in &rules {
-            if let Some(message) = rule.check(doc) {
-                total_issues += 1;
-                resource_issues.push(message);
-            }
-        }
+/// Everything a lint run reads that can change while watching: the config
+/// and the paths, which may come from an input list.
+pub struct Inputs {
+    pub paths: Vec<String>,
+    pub config: Config,
+}
+
+/// Lints `inputs` once and exits with the resulting status, or, with
+/// `watch` files, keeps re-linting on changes to the manifests or to those
+/// files, calling `reload` to pick up config and input-list edits.
+pub fn run_lint(
+    inputs: Inputs,
+    reload: &dyn Fn() -> rustykube_core::Result<Inputs>,
+    discovery: Discovery,
+    files: FileOptions,
+    report: ReportOptions,
+    watch: Option<Vec<PathBuf>>,
+    selected_rules: &[String],
+) {
+    let style = Style::new(inputs.config.output.plain);
+    let discovery = if discovery.gitignore { discovery.with_ignore_file() } else { Ok(discovery) };
+    let discovery = discovery.unwrap_or_else(|err| {
+        eprintln!("{} {}", style.error(), err);
+        process::exit(EXIT_ERROR);
+    });
 
-        if resource_issues.is_empty() {
-            println!("  ✅ No issues found.\n");
-        } else {
-            for issue in &resource_issues {
-                println!("  ❌ {}", issue);
+    let Some(watched_files) = watch else {
+        let linter = linter(inputs.config, &files, selected_rules);
+        let status = lint_paths(&linter, &discovery, &inputs.paths, &report, selected_rules, style).unwrap_or_else(|err| {
+            eprintln!("{} {}", style.error(), err);
+            EXIT_ERROR
+        });
+        process::exit(status);
+    };
+
+    let mut pending = Some(inputs);
+    super::watch::watch(&discovery, &watched_files, style, || {
+        let Inputs { paths, config } = match pending.take().map_or_else(reload, Ok) {
+            Ok(inputs) => inputs,
+            Err(err) => {
+                eprintln!("{} {}", style.error(), err);
+                return None;
             }
-            println!();
+        };
+        let linter = linter(config, &files, selected_rules);
+        if let Err(err) = lint_paths(&linter, &discovery, &paths, &report, selected_rules, style) {
+            eprintln!("{} {}", style.error(), err);
         }
+        Some(paths)
+    });
+}
 
-        results.push((format!("Resource {}", i + 1), resource_issues));
-    }
+fn linter(config: Config, files: &FileOptions, selected_rules: &[String]) -> Linter {
+    Linter::new(lint_rules::default_rules(&config), config)
+        .with_max_file_size(files.max_file_size)
+        .with_prescan(files.prescan)
+        .with_include_non_kubernetes(files.include_non_kubernetes)
+        .with_targets(files.targets.clone())
+        .with_selected_rules(selected_rules.to_vec())
+}
 
-    // Final Summary
-    println!("--- Summary ---");
-    if total_issues == 0 {
-        println!("🎉 All Resources passed linting with no issues!\n");
-    } else {
-        println!(
-            "⚠️  Linting completed with {} issue(s) across {} resource(s).\n",
-            total_issues,
-            docs.len()
-        );
+/// Lints and writes every output, returning the exit status. The summary
+/// names any of `selected_rules` that matched no rule or had nothing in
+/// the input to check, so it is not mistaken for a clean pass.
+fn lint_paths(
+    linter: &Linter,
+    discovery: &Discovery,
+    paths: &[String],
+    report: &ReportOptions,
+    selected_rules: &[String],
+    style: Style,
+) -> rustykube_core::Result<i32> {
+    let files: Vec<String> = discovery.resolve(paths)?.iter().map(|file| file.to_string_lossy().into_owned()).collect();
+
+    let mut reports = vec![];
+    let mut unreadable = false;
+    for result in linter.lint_files_with(&files, &mut ()) {
+        match result {
+            Ok(report) => reports.push(report),
+            Err(err) => {
+                eprintln!("{} {}", style.error(), err);
+                unreadable = true;
+            }
+        }
     }
 
-    if json {
-        let json_output: Vec<_> = results
-            .into_iter()
-            .map(|(doc, issues)| {
-                serde_json::json!({
-                    "document": doc,
-                    "issues": issues,
-                })
-            })
-            .collect();
+    let applicable = |id: &str| reports.iter().flat_map(|r| &r.resources).any(|resource| resource.rules.contains(&id));
+    let not_applicable: Vec<&str> = selected_rules.iter().map(String::as_str).filter(|id| !applicable(id)).collect();
 
-        println!("{}", serde_json::to_string_pretty(&json_output).unwrap());
+    for output in &report.outputs {
+        output.write(&reports, &not_applicable, style)?;
     }
+
+    let failed = reports
+        .iter()
+        .flat_map(|r| &r.resources)
+        .flat_map(|resource| &resource.issues)
+        .any(|issue| issue.severity >= report.fail_on);
+    Ok(if unreadable {
+        EXIT_ERROR
+    } else if failed {
+        EXIT_FINDINGS
+    } else {
+        0
+    })
 }
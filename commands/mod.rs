@@ -1 +1,5 @@
 pub mod lint;
+pub mod output;
+pub mod watch;
+pub mod bench;
+pub mod style;
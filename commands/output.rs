@@ -0,0 +1,334 @@
+use std::fs;
+use std::io::{self, Write};
+use std::str::FromStr;
+
+use rustykube_core::lint_rules::Severity;
+use rustykube_core::linter::{Finding, LintReport, SkipReason, Skipped};
+use rustykube_core::{Error, Result};
+
+use super::style::Style;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Human,
+    Json,
+    Sarif,
+    Junit,
+}
+
+/// A report format and the file it goes to, or stdout when `path` is
+/// `None`. Parsed from `format` or `format:path`, e.g. `sarif:out.sarif`.
+#[derive(Debug, Clone)]
+pub struct Output {
+    pub format: Format,
+    pub path: Option<String>,
+}
+
+impl FromStr for Output {
+    type Err = String;
+
+    fn from_str(spec: &str) -> std::result::Result<Self, Self::Err> {
+        let (format, path) = match spec.split_once(':') {
+            Some((format, path)) => (format, Some(path.to_string())),
+            None => (spec, None),
+        };
+        let format = match format {
+            "human" => Format::Human,
+            "json" => Format::Json,
+            "sarif" => Format::Sarif,
+            "junit" => Format::Junit,
+            _ => return Err(format!("unknown format '{}'; expected human, json, sarif or junit", format)),
+        };
+        Ok(Output { format, path })
+    }
+}
+
+impl Output {
+    pub fn stdout(format: Format) -> Self {
+        Output { format, path: None }
+    }
+
+    /// `not_applicable` lists rules that ran without any resource to check.
+    pub fn write(&self, reports: &[LintReport], not_applicable: &[&str], style: Style) -> Result<()> {
+        let path = self.path.as_deref().unwrap_or("stdout");
+        let write_error = |source| Error::Write { path: path.to_string(), source };
+        let mut out: Box<dyn Write> = match &self.path {
+            Some(path) => Box::new(io::BufWriter::new(fs::File::create(path).map_err(write_error)?)),
+            None => Box::new(io::stdout().lock()),
+        };
+        let written = match self.format {
+            Format::Human if self.path.is_some() => human(&mut out, reports, not_applicable, style.without_links()),
+            Format::Human => human(&mut out, reports, not_applicable, style),
+            Format::Json => json(&mut out, reports, not_applicable),
+            Format::Sarif => sarif(&mut out, reports),
+            Format::Junit => junit(&mut out, reports),
+        };
+        written.and_then(|_| out.flush()).map_err(write_error)
+    }
+}
+
+fn human(out: &mut dyn Write, reports: &[LintReport], not_applicable: &[&str], style: Style) -> io::Result<()> {
+    writeln!(out, "\n--- Linting Results ---\n")?;
+
+    for report in reports {
+        writeln!(out, "{} {}\n", style.file(), report.path)?;
+
+        for resource in &report.resources {
+            writeln!(out, "{} Resource {}, of Type: {}:", style.resource(), resource.name, resource.kind)?;
+
+            if resource.issues.is_empty() {
+                writeln!(out, "  {} No issues found.\n", style.ok())?;
+            } else {
+                for issue in &resource.issues {
+                    let icon = match issue.severity {
+                        Severity::Warning => style.warning(),
+                        Severity::Error => style.error(),
+                        Severity::Critical => style.critical(),
+                    };
+                    writeln!(out, "  {} {} ({}, {})", icon, issue.message, position(&report.path, issue), issue.field)?;
+                    if let Some(docs) = &issue.docs {
+                        writeln!(out, "     {} {}", style.docs(), style.link(docs))?;
+                    }
+                }
+                writeln!(out)?;
+            }
+        }
+    }
+
+    let skipped: Vec<_> = reports.iter().flat_map(|r| &r.skipped).collect();
+    if !skipped.is_empty() {
+        writeln!(out, "--- Skipped ---")?;
+        for skip in &skipped {
+            match (skip.document, &skip.reason) {
+                (Some(index), SkipReason::ParseError(err)) => {
+                    writeln!(out, "  {} {} (document {}): {}: {}", style.skipped(), skip.path, index + 1, skip.reason, err)?
+                }
+                (None, SkipReason::TooLarge { size, limit }) => {
+                    writeln!(out, "  {} {}: {} ({} bytes, limit {})", style.skipped(), skip.path, skip.reason, size, limit)?
+                }
+                (Some(index), _) => writeln!(out, "  {} {} (document {}): {}", style.skipped(), skip.path, index + 1, skip.reason)?,
+                (None, _) => writeln!(out, "  {} {}: {}", style.skipped(), skip.path, skip.reason)?,
+            }
+        }
+        writeln!(out)?;
+    }
+
+    // Final Summary
+    let total_issues: usize = reports.iter().map(|r| r.total_issues()).sum();
+    let total_resources: usize = reports.iter().map(|r| r.resources.len()).sum();
+    writeln!(out, "--- Summary ---")?;
+    if total_issues == 0 {
+        writeln!(out, "{} All Resources passed linting with no issues!\n", style.success())?;
+    } else {
+        writeln!(
+            out,
+            "{} Linting completed with {} issue(s) across {} resource(s) in {} file(s).\n",
+            style.warning(),
+            total_issues,
+            total_resources,
+            reports.len()
+        )?;
+    }
+    if !skipped.is_empty() {
+        writeln!(out, "{} Skipped {} file(s)/document(s): {}.\n", style.skipped(), skipped.len(), skip_breakdown(&skipped))?;
+    }
+    for rule in not_applicable {
+        writeln!(out, "{} Rule {} had nothing to check: no matching resources in the input, or turned off by config.", style.not_applicable(), rule)?;
+    }
+    if !not_applicable.is_empty() {
+        writeln!(out)?;
+    }
+    Ok(())
+}
+
+/// Version of the `--format json` schema, bumped on incompatible changes.
+/// Version 1 was a bare array of `{document, issues: [string]}`.
+const JSON_VERSION: u32 = 2;
+
+fn json(out: &mut dyn Write, reports: &[LintReport], not_applicable: &[&str]) -> io::Result<()> {
+    let results: Vec<_> = reports
+        .iter()
+        .flat_map(|report| report.resources.iter().map(move |resource| (report, resource)))
+        .map(|(report, resource)| {
+            let issues: Vec<_> = resource
+                .issues
+                .iter()
+                .map(|issue| {
+                    serde_json::json!({
+                        "rule": issue.rule,
+                        "severity": issue.severity.as_str(),
+                        "message": issue.message,
+                        "field": issue.field,
+                        "line": issue.location.map(|l| l.line),
+                        "column": issue.location.map(|l| l.column),
+                        "docs": issue.docs,
+                    })
+                })
+                .collect();
+
+            serde_json::json!({
+                "file": report.path,
+                "document": format!("Resource {}", resource.index + 1),
+                "documentIndex": resource.index,
+                "line": resource.line,
+                "issues": issues,
+            })
+        })
+        .collect();
+
+    let skipped_json: Vec<_> = reports
+        .iter()
+        .flat_map(|r| &r.skipped)
+        .map(|skip| {
+            serde_json::json!({
+                "file": skip.path,
+                "documentIndex": skip.document,
+                "reason": skip.reason.to_string(),
+                "error": match &skip.reason {
+                    SkipReason::ParseError(err) => Some(err),
+                    _ => None,
+                },
+            })
+        })
+        .collect();
+
+    let json_output = serde_json::json!({
+        "version": JSON_VERSION,
+        "results": results,
+        "skipped": skipped_json,
+        "notApplicable": not_applicable,
+    });
+    serde_json::to_writer_pretty(&mut *out, &json_output)?;
+    writeln!(out)
+}
+
+/// SARIF 2.1.0, the format code scanning dashboards ingest.
+fn sarif(out: &mut dyn Write, reports: &[LintReport]) -> io::Result<()> {
+    let mut rules: Vec<serde_json::Value> = vec![];
+    let mut results = vec![];
+    for report in reports {
+        for issue in report.resources.iter().flat_map(|r| &r.issues) {
+            if !rules.iter().any(|rule| rule["id"] == issue.rule) {
+                let mut rule = serde_json::json!({ "id": issue.rule });
+                if let Some(docs) = &issue.docs {
+                    rule["helpUri"] = serde_json::json!(docs);
+                }
+                rules.push(rule);
+            }
+
+            let mut location = serde_json::json!({ "artifactLocation": { "uri": report.path } });
+            if let Some(l) = issue.location {
+                location["region"] = serde_json::json!({ "startLine": l.line, "startColumn": l.column });
+            }
+            results.push(serde_json::json!({
+                "ruleId": issue.rule,
+                "level": match issue.severity {
+                    Severity::Warning => "warning",
+                    Severity::Error | Severity::Critical => "error",
+                },
+                "message": { "text": format!("{} ({})", issue.message, issue.field) },
+                "locations": [{ "physicalLocation": location }],
+            }));
+        }
+    }
+
+    let sarif = serde_json::json!({
+        "$schema": "https://json.schemastore.org/sarif-2.1.0.json",
+        "version": "2.1.0",
+        "runs": [{
+            "tool": { "driver": { "name": "rustykube", "version": env!("CARGO_PKG_VERSION"), "rules": rules } },
+            "results": results,
+        }],
+    });
+    serde_json::to_writer_pretty(&mut *out, &sarif)?;
+    writeln!(out)
+}
+
+/// JUnit XML for CI test report viewers: a suite per file, a test case per
+/// resource failing once per finding, with the rule's docs link if any, and
+/// skipped documents as skipped.
+fn junit(out: &mut dyn Write, reports: &[LintReport]) -> io::Result<()> {
+    let tests = |report: &LintReport| report.resources.len() + report.skipped.len();
+    let failures = |report: &LintReport| report.resources.iter().filter(|r| !r.issues.is_empty()).count();
+
+    writeln!(out, r#"<?xml version="1.0" encoding="UTF-8"?>"#)?;
+    writeln!(
+        out,
+        r#"<testsuites name="rustykube" tests="{}" failures="{}">"#,
+        reports.iter().map(tests).sum::<usize>(),
+        reports.iter().map(failures).sum::<usize>()
+    )?;
+    for report in reports {
+        let path = xml_escape(&report.path);
+        writeln!(
+            out,
+            r#"  <testsuite name="{}" tests="{}" failures="{}" skipped="{}">"#,
+            path,
+            tests(report),
+            failures(report),
+            report.skipped.len()
+        )?;
+        for resource in &report.resources {
+            let name = xml_escape(&format!("{} {} (document {})", resource.kind, resource.name, resource.index + 1));
+            if resource.issues.is_empty() {
+                writeln!(out, r#"    <testcase classname="{}" name="{}"/>"#, path, name)?;
+                continue;
+            }
+            writeln!(out, r#"    <testcase classname="{}" name="{}">"#, path, name)?;
+            for issue in &resource.issues {
+                let docs = issue.docs.as_ref().map(|docs| format!("\nSee {}", xml_escape(docs))).unwrap_or_default();
+                writeln!(
+                    out,
+                    r#"      <failure type="{}" message="{}">{} ({}, {}){}</failure>"#,
+                    issue.rule,
+                    xml_escape(&issue.message),
+                    issue.severity.as_str(),
+                    xml_escape(&position(&report.path, issue)),
+                    xml_escape(&issue.field),
+                    docs
+                )?;
+            }
+            writeln!(out, "    </testcase>")?;
+        }
+        for skip in &report.skipped {
+            let name = match skip.document {
+                Some(index) => format!("document {}", index + 1),
+                None => "file".to_string(),
+            };
+            writeln!(out, r#"    <testcase classname="{}" name="{}">"#, path, name)?;
+            writeln!(out, r#"      <skipped message="{}"/>"#, xml_escape(&skip.reason.to_string()))?;
+            writeln!(out, "    </testcase>")?;
+        }
+        writeln!(out, "  </testsuite>")?;
+    }
+    writeln!(out, "</testsuites>")
+}
+
+fn xml_escape(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('"', "&quot;")
+}
+
+/// `file:line:column`, the form editors and CI annotators link on.
+fn position(path: &str, finding: &Finding) -> String {
+    match finding.location {
+        Some(location) => format!("{}:{}:{}", path, location.line, location.column),
+        None => path.to_string(),
+    }
+}
+
+/// Counts per reason, e.g. `2 templated, 1 parse error`.
+fn skip_breakdown(skipped: &[&Skipped]) -> String {
+    let mut counts: Vec<(String, usize)> = vec![];
+    for skip in skipped {
+        let reason = skip.reason.to_string();
+        match counts.iter_mut().find(|(r, _)| *r == reason) {
+            Some((_, count)) => *count += 1,
+            None => counts.push((reason, 1)),
+        }
+    }
+    counts
+        .iter()
+        .map(|(reason, count)| format!("{} {}", count, reason))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
@@ -0,0 +1,79 @@
+use std::io::{self, IsTerminal};
+
+/// Markers used in terminal output: emojis, or ASCII tags for CI logs and
+/// terminals that mangle them.
+#[derive(Debug, Clone, Copy)]
+pub struct Style {
+    plain: bool,
+    links: bool,
+}
+
+impl Style {
+    /// Links are only emitted as hyperlinks when styled and stdout is a
+    /// terminal.
+    pub fn new(plain: bool) -> Self {
+        Style { plain, links: !plain && io::stdout().is_terminal() }
+    }
+
+    /// The same markers without hyperlinks, for output written to a file.
+    pub fn without_links(self) -> Self {
+        Style { links: false, ..self }
+    }
+
+    fn pick(self, emoji: &'static str, plain: &'static str) -> &'static str {
+        if self.plain { plain } else { emoji }
+    }
+
+    pub fn error(self) -> &'static str {
+        self.pick("❌", "[error]")
+    }
+
+    pub fn warning(self) -> &'static str {
+        self.pick("⚠️ ", "[warning]")
+    }
+
+    pub fn critical(self) -> &'static str {
+        self.pick("🚨", "[critical]")
+    }
+
+    pub fn ok(self) -> &'static str {
+        self.pick("✅", "[ok]")
+    }
+
+    pub fn file(self) -> &'static str {
+        self.pick("📁", "File:")
+    }
+
+    pub fn resource(self) -> &'static str {
+        self.pick("📄", "-")
+    }
+
+    pub fn docs(self) -> &'static str {
+        self.pick("📖", "docs:")
+    }
+
+    pub fn skipped(self) -> &'static str {
+        self.pick("⏭️ ", "[skipped]")
+    }
+
+    pub fn not_applicable(self) -> &'static str {
+        self.pick("💤", "[n/a]")
+    }
+
+    pub fn success(self) -> &'static str {
+        self.pick("🎉", "[ok]")
+    }
+
+    pub fn watching(self) -> &'static str {
+        self.pick("👀", "[watch]")
+    }
+
+    pub fn rerun(self) -> &'static str {
+        self.pick("🔄", "[watch]")
+    }
+
+    /// `url` as an OSC 8 terminal hyperlink, or plain text.
+    pub fn link(self, url: &str) -> String {
+        if self.links { format!("\x1b]8;;{}\x1b\\{}\x1b]8;;\x1b\\", url, url) } else { url.to_string() }
+    }
+}
@@ -0,0 +1,115 @@
+use std::path::{self, Path, PathBuf};
+use std::process;
+use std::sync::mpsc;
+use std::time::Duration;
+
+use notify::{Event, EventKind, RecursiveMode, Watcher};
+use rustykube_core::discovery::{self, Discovery};
+
+use super::lint::EXIT_ERROR;
+use super::style::Style;
+
+/// How long to wait for more events after the first, so one save that
+/// touches several files triggers a single run.
+const DEBOUNCE: Duration = Duration::from_millis(200);
+
+/// Calls `run`, then again every time a manifest under the paths it returns
+/// or one of `files` (config, input list) is added, removed or modified.
+/// `run` returns `None` when it could not load its inputs, keeping the
+/// previous paths watched. Uses the platform's filesystem notifier, so idle
+/// watching costs nothing however large the tree. Never returns.
+pub fn watch(discovery: &Discovery, files: &[PathBuf], style: Style, mut run: impl FnMut() -> Option<Vec<String>>) {
+    let (sender, events) = mpsc::channel();
+    let mut watcher = notify::recommended_watcher(sender).unwrap_or_else(|err| {
+        eprintln!("{} Cannot watch for changes: {}", style.error(), err);
+        process::exit(EXIT_ERROR);
+    });
+    let files: Vec<PathBuf> = files.iter().map(|file| absolute(file)).collect();
+    let mut targets = Targets::default();
+
+    loop {
+        if let Some(paths) = run() {
+            let next = Targets::new(discovery, &paths, &files);
+            next.update(&mut watcher, &targets, style);
+            targets = next;
+        }
+        println!("{} Watching for changes... (Ctrl+C to stop)\n", style.watching());
+
+        loop {
+            match events.recv() {
+                Ok(Ok(event)) if targets.is_relevant(discovery, &event) => break,
+                Ok(_) => continue,
+                Err(_) => return,
+            }
+        }
+        while events.recv_timeout(DEBOUNCE).is_ok() {}
+        println!("\n{} Change detected, re-running...", style.rerun());
+    }
+}
+
+/// What is being watched: directories recursively for manifests, and
+/// individual files through their parent directory, since editors often
+/// save by replacing the file.
+#[derive(Default)]
+struct Targets {
+    dirs: Vec<PathBuf>,
+    files: Vec<PathBuf>,
+}
+
+impl Targets {
+    fn new(discovery: &Discovery, paths: &[String], files: &[PathBuf]) -> Self {
+        let mut targets = Targets { dirs: vec![], files: files.to_vec() };
+        for root in discovery.roots(paths).iter().map(|root| absolute(root)) {
+            if root.is_dir() {
+                targets.dirs.push(root);
+            } else {
+                targets.files.push(root);
+            }
+        }
+        targets
+    }
+
+    /// Every path handed to the watcher, with how deep to watch it.
+    fn watches(&self) -> Vec<(PathBuf, RecursiveMode)> {
+        let mut watches: Vec<(PathBuf, RecursiveMode)> = vec![];
+        let dirs = self.dirs.iter().map(|dir| (dir.clone(), RecursiveMode::Recursive));
+        let parents = self.files.iter().filter_map(|file| file.parent()).map(|dir| (dir.to_path_buf(), RecursiveMode::NonRecursive));
+        for watch in dirs.chain(parents) {
+            if !watches.contains(&watch) {
+                watches.push(watch);
+            }
+        }
+        watches
+    }
+
+    /// Moves `watcher` from `previous`'s watches to these.
+    fn update(&self, watcher: &mut impl Watcher, previous: &Targets, style: Style) {
+        let (old, new) = (previous.watches(), self.watches());
+        for (path, _) in old.iter().filter(|watch| !new.contains(watch)) {
+            let _ = watcher.unwatch(path);
+        }
+        for (path, mode) in new.iter().filter(|watch| !old.contains(watch)) {
+            if let Err(err) = watcher.watch(path, *mode) {
+                eprintln!("{} Cannot watch {}: {}", style.warning(), path.display(), err);
+            }
+        }
+    }
+
+    /// Whether `event` changed a watched file or a manifest the discovery
+    /// settings would pick up.
+    fn is_relevant(&self, discovery: &Discovery, event: &Event) -> bool {
+        if matches!(event.kind, EventKind::Access(_)) {
+            return false;
+        }
+        event.paths.iter().any(|path| {
+            self.files.contains(path)
+                || (self.dirs.iter().any(|dir| path.starts_with(dir))
+                    && discovery::is_yaml_file(path)
+                    && !discovery.is_excluded(path, false))
+        })
+    }
+}
+
+fn absolute(path: &Path) -> PathBuf {
+    path::absolute(path).unwrap_or_else(|_| path.to_path_buf())
+}
@@ -0,0 +1,438 @@
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+use crate::utils;
+use crate::{Error, Result};
+
+pub const DEFAULT_CONFIG_FILE: &str = ".rustykube.yaml";
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields, rename_all = "kebab-case")]
+pub struct Config {
+    /// Rules that never run, regardless of path or kind.
+    pub disabled_rules: Vec<String>,
+    /// Scoped rule toggles, applied in order so later entries win.
+    pub overrides: Vec<RuleOverride>,
+    pub sample_manifests: SampleManifestsConfig,
+    pub cluster_profile: ClusterProfile,
+    pub zero_downtime: ZeroDowntimeConfig,
+    pub host_path: HostPathConfig,
+    pub storage: StorageConfig,
+    pub capabilities: CapabilitiesConfig,
+    pub namespace: NamespaceConfig,
+    pub config_reload: ConfigReloadConfig,
+    pub config_references: ConfigReferencesConfig,
+    pub recommended_labels: RecommendedLabelsConfig,
+    pub images: ImagesConfig,
+    pub qos: QosConfig,
+    pub cpu_limits: CpuLimitsPolicy,
+    pub probes: ProbesConfig,
+    pub read_only_root: ReadOnlyRootConfig,
+    pub load_balancers: LoadBalancersConfig,
+    pub external_traffic: ExternalTrafficConfig,
+    pub ingress: IngressConfig,
+    pub slo: SloConfig,
+    pub output: OutputConfig,
+    pub priority_class: PriorityClassConfig,
+    pub rbac: RbacConfig,
+    pub network_policy: NetworkPolicyConfig,
+    /// Remediation doc URL per rule id, attached to that rule's findings.
+    pub rule_docs: BTreeMap<String, String>,
+}
+
+/// What the target cluster looks like, for rules that only matter on some
+/// clusters.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields, rename_all = "kebab-case")]
+pub struct ClusterProfile {
+    /// The cluster mixes Windows and Linux nodes.
+    pub windows_nodes: bool,
+    /// The cluster runs production workloads, which must survive losing a pod.
+    pub production: bool,
+    /// A local development cluster (kind, minikube) with images loaded
+    /// directly onto its nodes.
+    pub dev: bool,
+    /// Nodes are spread across availability zones.
+    pub multi_zone: bool,
+    /// Bare-metal or on-prem nodes without a cloud load balancer, where
+    /// NodePort Services are a sanctioned way to expose workloads.
+    pub on_prem: bool,
+    /// Target Kubernetes version, e.g. `1.29`, for rules whose advice
+    /// depends on it.
+    pub kubernetes_version: Option<String>,
+}
+
+impl ClusterProfile {
+    /// `kubernetes_version` as `(major, minor)`, if set and valid.
+    pub fn version(&self) -> Option<(u32, u32)> {
+        self.kubernetes_version.as_deref().and_then(utils::parse_kubernetes_version)
+    }
+}
+
+/// Enables or disables rules for resources matching `paths` and `kinds`.
+/// An empty `paths` or `kinds` list matches everything.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields, rename_all = "kebab-case")]
+pub struct RuleOverride {
+    pub paths: Vec<String>,
+    pub kinds: Vec<String>,
+    pub disable: Vec<String>,
+    pub enable: Vec<String>,
+}
+
+/// Settings for the `sample-manifest` rule. Patterns are globs; names are
+/// matched lowercased.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields, rename_all = "kebab-case")]
+pub struct SampleManifestsConfig {
+    pub production_paths: Vec<String>,
+    pub name_patterns: Vec<String>,
+    pub sample_images: Vec<String>,
+}
+
+impl Default for SampleManifestsConfig {
+    fn default() -> Self {
+        let strings = |values: &[&str]| values.iter().map(|v| v.to_string()).collect();
+        SampleManifestsConfig {
+            production_paths: strings(&["**/prod/**", "**/production/**", "**/overlays/prod*/**"]),
+            name_patterns: strings(&["*example*", "*sample*", "*test*", "*demo*", "foo", "foo-*", "bar", "my-app"]),
+            sample_images: strings(&["nginx", "nginx:latest", "busybox*", "hello-world*", "*/hello-app:*"]),
+        }
+    }
+}
+
+/// Labels marking a workload as zero-downtime critical for the
+/// `zero-downtime-strategy` rule.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields, rename_all = "kebab-case")]
+pub struct ZeroDowntimeConfig {
+    pub selector: BTreeMap<String, String>,
+}
+
+impl Default for ZeroDowntimeConfig {
+    fn default() -> Self {
+        ZeroDowntimeConfig {
+            selector: BTreeMap::from([("rustykube.io/zero-downtime".to_string(), "true".to_string())]),
+        }
+    }
+}
+
+/// hostPath globs the `host-path` rule accepts, e.g. `/var/log/**`.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields, rename_all = "kebab-case")]
+pub struct HostPathConfig {
+    pub allowed_paths: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields, rename_all = "kebab-case")]
+pub struct StorageConfig {
+    /// Storage classes whose volumes must never be deleted automatically.
+    pub precious_storage_classes: Vec<String>,
+}
+
+/// Capabilities the `capabilities` rule refuses in `add:`, without the
+/// `CAP_` prefix.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields, rename_all = "kebab-case")]
+pub struct CapabilitiesConfig {
+    pub dangerous: Vec<String>,
+}
+
+impl Default for CapabilitiesConfig {
+    fn default() -> Self {
+        let dangerous = ["SYS_ADMIN", "NET_ADMIN", "NET_RAW", "SYS_PTRACE", "SYS_MODULE", "SYS_RAWIO", "DAC_READ_SEARCH", "BPF", "ALL"];
+        CapabilitiesConfig { dangerous: dangerous.iter().map(|c| c.to_string()).collect() }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields, rename_all = "kebab-case")]
+pub struct NamespaceConfig {
+    /// Namespaces are injected at deploy time (kustomize, `kubectl -n`), so
+    /// an unset `metadata.namespace` is fine.
+    pub allow_unset: bool,
+    /// Globs of directories that only hold cluster-scoped resources.
+    pub cluster_paths: Vec<String>,
+    /// Namespaces that exist in the cluster without a manifest here.
+    pub existing: Vec<String>,
+    /// Cluster-scoped custom resource kinds, e.g. `ClusterIssuer`, which
+    /// the namespace rules cannot tell from namespaced ones.
+    pub cluster_scoped_kinds: Vec<String>,
+}
+
+impl Default for NamespaceConfig {
+    fn default() -> Self {
+        let existing = ["default", "kube-system", "kube-public", "kube-node-lease"];
+        NamespaceConfig {
+            allow_unset: false,
+            cluster_paths: vec![],
+            existing: existing.iter().map(|n| n.to_string()).collect(),
+            cluster_scoped_kinds: vec![],
+        }
+    }
+}
+
+/// Workload annotations that make a reloader controller restart pods when
+/// their ConfigMaps or Secrets change, accepted by the `config-reload` rule.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields, rename_all = "kebab-case")]
+pub struct ConfigReloadConfig {
+    pub reloader_annotations: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields, rename_all = "kebab-case")]
+pub struct ConfigReferencesConfig {
+    /// Report ConfigMaps and Secrets (image pull Secrets included), and
+    /// StatefulSet headless Services, missing from the linted files as
+    /// warnings, for setups that create them outside the repo.
+    pub missing_as_warning: bool,
+}
+
+/// Labels every resource must carry for the `recommended-labels` rule.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields, rename_all = "kebab-case")]
+pub struct RecommendedLabelsConfig {
+    pub required: Vec<String>,
+}
+
+impl Default for RecommendedLabelsConfig {
+    fn default() -> Self {
+        let required = ["name", "instance", "version", "part-of", "managed-by"];
+        RecommendedLabelsConfig { required: required.iter().map(|l| format!("app.kubernetes.io/{}", l)).collect() }
+    }
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields, rename_all = "kebab-case")]
+pub struct ImagesConfig {
+    /// Repository globs images must come from, e.g. `ghcr.io/myorg/*`.
+    pub allowed_registries: Vec<String>,
+    /// Require digest-pinned images; only enforced on production clusters.
+    pub require_digests: bool,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields, rename_all = "kebab-case")]
+pub struct QosConfig {
+    /// Require equal CPU and memory requests and limits (Guaranteed QoS)
+    /// instead of only equal memory.
+    pub require_guaranteed: bool,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields, rename_all = "kebab-case")]
+pub struct ProbesConfig {
+    /// Globs of image names (without registry or tag) that take long to
+    /// start, such as JVM applications.
+    pub slow_start_images: Vec<String>,
+    /// Longest liveness `initialDelaySeconds` accepted without a startupProbe.
+    pub max_initial_delay: i64,
+}
+
+impl Default for ProbesConfig {
+    fn default() -> Self {
+        let images = ["elasticsearch", "opensearch", "keycloak", "jenkins", "sonarqube", "nexus*", "kafka", "cp-kafka", "*-jvm", "solr"];
+        ProbesConfig { slow_start_images: images.iter().map(|i| i.to_string()).collect(), max_initial_delay: 30 }
+    }
+}
+
+/// Paths apps commonly write to, which the `writable-paths` rule expects a
+/// writable volume at when the root filesystem is read-only.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields, rename_all = "kebab-case")]
+pub struct ReadOnlyRootConfig {
+    pub writable_paths: Vec<String>,
+}
+
+impl Default for ReadOnlyRootConfig {
+    fn default() -> Self {
+        ReadOnlyRootConfig { writable_paths: vec!["/tmp".to_string(), "/var/log".to_string()] }
+    }
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields, rename_all = "kebab-case")]
+pub struct LoadBalancersConfig {
+    /// Globs of paths whose LoadBalancer Services must be internal.
+    pub internal_paths: Vec<String>,
+    /// Every LoadBalancer, internal ones included, must set source ranges.
+    pub restricted: bool,
+    /// CIDRs source ranges must be drawn from in restricted environments.
+    pub source_ranges: Vec<String>,
+}
+
+/// What the `external-traffic-policy` rule expects of LoadBalancer and
+/// NodePort Services.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields, rename_all = "kebab-case")]
+pub struct ExternalTrafficConfig {
+    /// Workloads need the client source IP (allowlists, audit logs), so
+    /// `externalTrafficPolicy: Local` is required.
+    pub preserve_source_ip: bool,
+    /// Load balancer health checks are firewalled to known ports, so `Local`
+    /// LoadBalancers must pin `healthCheckNodePort`.
+    pub pin_health_check_port: bool,
+    /// Clients reach NodePorts on any node, so `Local` NodePorts drop some
+    /// of them. Defaults to `cluster-profile.on-prem`, where NodePorts take
+    /// client traffic directly; turn off when an external balancer health
+    /// checks the node ports.
+    pub warn_local_node_ports: Option<bool>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields, rename_all = "kebab-case")]
+pub struct IngressConfig {
+    /// Host globs intentionally served without TLS.
+    pub plaintext_hosts: Vec<String>,
+}
+
+/// Annotations production workloads must carry for the `slo-annotations`
+/// rule, e.g. `slo.company.io/target: percentage`.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields, rename_all = "kebab-case")]
+pub struct SloConfig {
+    pub annotations: BTreeMap<String, AnnotationFormat>,
+}
+
+/// The value an annotation required by the `slo-annotations` rule must
+/// have: `any`, `percentage`, `duration`, or otherwise a glob.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(from = "String")]
+pub enum AnnotationFormat {
+    /// Any non-empty value.
+    #[default]
+    Any,
+    /// A number in (0, 100], optionally followed by `%`.
+    Percentage,
+    /// A Prometheus-style duration such as `30d` or `1h30m`.
+    Duration,
+    /// A glob the value must match, e.g. `#*` for a Slack channel.
+    Pattern(String),
+}
+
+impl From<String> for AnnotationFormat {
+    fn from(format: String) -> Self {
+        match format.as_str() {
+            "any" => AnnotationFormat::Any,
+            "percentage" => AnnotationFormat::Percentage,
+            "duration" => AnnotationFormat::Duration,
+            _ => AnnotationFormat::Pattern(format),
+        }
+    }
+}
+
+/// Where the opt-in `priority-class` rule requires `priorityClassName`:
+/// workloads in any of the namespaces or under any of the paths.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields, rename_all = "kebab-case")]
+pub struct PriorityClassConfig {
+    pub critical_namespaces: Vec<String>,
+    /// Path globs, e.g. `**/critical/**`.
+    pub critical_paths: Vec<String>,
+}
+
+/// How strictly the `network-policy` rule treats workloads no
+/// NetworkPolicy selects.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields, rename_all = "kebab-case")]
+pub struct NetworkPolicyConfig {
+    /// The cluster is default-deny, so missing ingress coverage is an error
+    /// rather than a warning.
+    pub missing_ingress_as_error: bool,
+    /// Also check Jobs and CronJobs, which rarely accept connections.
+    pub include_batch: bool,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields, rename_all = "kebab-case")]
+pub struct RbacConfig {
+    /// Globs of binding names the `rbac-bindings` rule accepts, such as
+    /// break-glass bindings owned by the platform team.
+    pub allowed_bindings: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields, rename_all = "kebab-case")]
+pub struct OutputConfig {
+    /// Print ASCII tags instead of emojis, for CI logs and terminals that
+    /// mangle them.
+    pub plain: bool,
+}
+
+/// What the `cpu-limits` rule enforces.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum CpuLimitsPolicy {
+    #[default]
+    Ignore,
+    /// CPU limits cause throttling; containers must not set them.
+    Forbid,
+    /// Every container must set a CPU limit.
+    Require,
+}
+
+impl Config {
+    /// Loads `path` if given, otherwise `.rustykube.yaml` from the working
+    /// directory when present, otherwise the default config.
+    pub fn load(path: Option<&str>) -> Result<Config> {
+        let path = match path {
+            Some(path) => path,
+            None if Path::new(DEFAULT_CONFIG_FILE).exists() => DEFAULT_CONFIG_FILE,
+            None => return Ok(Config::default()),
+        };
+
+        let contents = fs::read_to_string(path).map_err(|source| Error::Io { path: path.to_string(), source })?;
+        if contents.trim().is_empty() {
+            return Ok(Config::default());
+        }
+        serde_yaml::from_str(&contents).map_err(|source| Error::Yaml { path: path.to_string(), source })
+    }
+
+    /// Fails on the first rule id in `disabled-rules`, `overrides` or
+    /// `rule-docs` that is not one of `known`, so a typo cannot silently
+    /// leave a rule on or off.
+    pub fn check_rule_ids(&self, known: &[&str]) -> Result<()> {
+        check_ids(&self.disabled_rules, known, || "disabled-rules".to_string())?;
+        for (i, rule_override) in self.overrides.iter().enumerate() {
+            check_ids(&rule_override.disable, known, || format!("overrides[{}].disable", i))?;
+            check_ids(&rule_override.enable, known, || format!("overrides[{}].enable", i))?;
+        }
+        check_ids(self.rule_docs.keys(), known, || "rule-docs".to_string())
+    }
+
+    pub fn is_rule_enabled(&self, rule: &str, file: &str, kind: &str) -> bool {
+        let mut enabled = !self.disabled_rules.iter().any(|r| r == rule);
+
+        for rule_override in self.overrides.iter().filter(|o| o.matches(file, kind)) {
+            if rule_override.disable.iter().any(|r| r == rule) {
+                enabled = false;
+            }
+            if rule_override.enable.iter().any(|r| r == rule) {
+                enabled = true;
+            }
+        }
+        enabled
+    }
+}
+
+/// Fails on the first of `ids` not in `known`, naming `origin`.
+pub fn check_ids<'a>(ids: impl IntoIterator<Item = &'a String>, known: &[&str], origin: impl FnOnce() -> String) -> Result<()> {
+    match ids.into_iter().find(|id| !known.contains(&id.as_str())) {
+        Some(id) => Err(Error::UnknownRule { id: id.clone(), origin: origin() }),
+        None => Ok(()),
+    }
+}
+
+impl RuleOverride {
+    fn matches(&self, file: &str, kind: &str) -> bool {
+        let file = file.trim_start_matches("./");
+        let path_matches = self.paths.is_empty()
+            || self.paths.iter().any(|pattern| utils::glob_match(pattern, file));
+        let kind_matches = self.kinds.is_empty() || self.kinds.iter().any(|k| k == kind);
+        path_matches && kind_matches
+    }
+}
@@ -0,0 +1,233 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::gitignore::Gitignore;
+use crate::utils;
+use crate::{Error, Result};
+
+pub const IGNORE_FILE: &str = ".rustykubeignore";
+
+/// Reads path arguments from a work list file (`-` for stdin): one file,
+/// directory or glob per line, relative to the working directory like
+/// command-line paths. Blank lines and `#` comments are ignored.
+pub fn read_input_list(list: &str) -> Result<Vec<String>> {
+    let contents = if list == "-" {
+        io::read_to_string(io::stdin())
+    } else {
+        fs::read_to_string(list)
+    };
+    let contents = contents.map_err(|source| Error::Io { path: list.to_string(), source })?;
+
+    let mut paths = vec![];
+    for line in contents.lines().map(str::trim).filter(|line| !line.is_empty() && !line.starts_with('#')) {
+        if line.starts_with("http://") || line.starts_with("https://") {
+            return Err(Error::UnsupportedUrl { url: line.to_string() });
+        }
+        paths.push(line.to_string());
+    }
+    Ok(paths)
+}
+
+/// Turns command-line path arguments into the manifest files to process.
+#[derive(Debug, Clone, Default)]
+pub struct Discovery {
+    /// How many directory levels to enter; `Some(1)` only lists the files
+    /// directly inside a directory argument.
+    pub max_depth: Option<usize>,
+    /// Exclude patterns, in `.rustykubeignore` syntax.
+    pub exclude: Vec<String>,
+    /// Skip files ignored by `.gitignore` files while walking directories.
+    pub gitignore: bool,
+}
+
+impl Discovery {
+    /// Adds the patterns from `.rustykubeignore` in the working directory,
+    /// if there is one. Blank lines and `#` comments are ignored.
+    pub fn with_ignore_file(mut self) -> Result<Self> {
+        if !Path::new(IGNORE_FILE).exists() {
+            return Ok(self);
+        }
+        let contents = fs::read_to_string(IGNORE_FILE).map_err(|source| Error::Io { path: IGNORE_FILE.to_string(), source })?;
+        self.exclude.extend(
+            contents
+                .lines()
+                .map(str::trim)
+                .filter(|line| !line.is_empty() && !line.starts_with('#'))
+                .map(str::to_string),
+        );
+        Ok(self)
+    }
+
+    /// Arguments may be files, directories or glob patterns; each file is
+    /// returned once, in argument order. Explicitly named files are never
+    /// excluded.
+    pub fn resolve(&self, args: &[String]) -> Result<Vec<PathBuf>> {
+        let mut files: Vec<PathBuf> = vec![];
+        for arg in args {
+            let found = if is_glob(arg) {
+                let matches = self.expand_glob(arg).map_err(|source| Error::Io { path: arg.clone(), source })?;
+                if matches.is_empty() {
+                    return Err(Error::NoMatches { pattern: arg.clone() });
+                }
+                matches
+            } else {
+                self.find_files(Path::new(arg)).map_err(|source| Error::Io { path: arg.clone(), source })?
+            };
+
+            for file in found {
+                if !files.contains(&file) {
+                    files.push(file);
+                }
+            }
+        }
+        Ok(files)
+    }
+
+    /// The files and directories whose contents `args` can resolve to:
+    /// each plain path, and the directory a glob starts matching in.
+    pub fn roots(&self, args: &[String]) -> Vec<PathBuf> {
+        args.iter().map(|arg| if is_glob(arg) { glob_base(arg) } else { PathBuf::from(arg) }).collect()
+    }
+
+    /// Expands a glob pattern against the filesystem. Matching YAML files are
+    /// returned directly; matching directories are scanned like a plain path.
+    /// `max_depth` counts from the directory the pattern starts matching in.
+    pub fn expand_glob(&self, pattern: &str) -> io::Result<Vec<PathBuf>> {
+        let base = glob_base(pattern);
+        if !base.is_dir() {
+            return Ok(vec![]);
+        }
+
+        let mut entries = vec![];
+        self.walk_entries(&base, 1, &mut entries, &self.gitignore_for(&base))?;
+        entries.sort();
+
+        let mut files = vec![];
+        for (entry, depth) in entries {
+            if !utils::glob_match(pattern, &entry.to_string_lossy()) {
+                continue;
+            }
+            if entry.is_dir() {
+                // The directory's contents are one level deeper than it.
+                let mut found = vec![];
+                self.walk(&entry, depth + 1, &mut found, &self.gitignore_for(&entry))?;
+                found.sort();
+                files.extend(found);
+            } else if is_yaml_file(&entry) {
+                files.push(entry);
+            }
+        }
+        Ok(files)
+    }
+
+    /// Collects `.yaml`/`.yml` files under `path`, sorted. A file path is
+    /// returned as-is.
+    pub fn find_files(&self, path: &Path) -> io::Result<Vec<PathBuf>> {
+        let mut files = vec![];
+        if path.is_dir() {
+            self.walk(path, 1, &mut files, &self.gitignore_for(path))?;
+            files.sort();
+        } else {
+            files.push(path.to_path_buf());
+        }
+        Ok(files)
+    }
+
+    /// Whether `path` matches an exclude pattern. Patterns containing a `/`
+    /// are matched against the whole path, others against the file or
+    /// directory name at any depth; a trailing `/` only matches directories.
+    pub fn is_excluded(&self, path: &Path, is_dir: bool) -> bool {
+        let path = path.to_string_lossy();
+        let path = path.trim_start_matches("./");
+        let name = path.rsplit('/').next().unwrap_or(path);
+
+        self.exclude.iter().any(|pattern| {
+            let (pattern, dir_only) = match pattern.strip_suffix('/') {
+                Some(pattern) => (pattern, true),
+                None => (pattern.as_str(), false),
+            };
+            if dir_only && !is_dir {
+                return false;
+            }
+            match pattern.strip_prefix('/') {
+                Some(anchored) => utils::glob_match(anchored, path),
+                None if pattern.contains('/') => utils::glob_match(pattern, path),
+                None => utils::glob_match(pattern, name),
+            }
+        })
+    }
+
+    fn gitignore_for(&self, root: &Path) -> Option<Gitignore> {
+        self.gitignore.then(|| Gitignore::for_root(root))
+    }
+
+    fn is_skipped(&self, path: &Path, is_dir: bool, gitignore: &Option<Gitignore>) -> bool {
+        self.is_excluded(path, is_dir) || gitignore.as_ref().is_some_and(|g| g.is_ignored(path, is_dir))
+    }
+
+    fn walk(&self, dir: &Path, depth: usize, files: &mut Vec<PathBuf>, gitignore: &Option<Gitignore>) -> io::Result<()> {
+        if self.max_depth.is_some_and(|max| depth > max) {
+            return Ok(());
+        }
+        let gitignore = gitignore.as_ref().map(|g| g.with_dir(dir));
+
+        for entry in fs::read_dir(dir)? {
+            let entry = entry?;
+            let path = entry.path();
+            let is_dir = entry.file_type()?.is_dir();
+            if self.is_skipped(&path, is_dir, &gitignore) {
+                continue;
+            }
+
+            if is_dir {
+                self.walk(&path, depth + 1, files, &gitignore)?;
+            } else if is_yaml_file(&path) {
+                files.push(path);
+            }
+        }
+        Ok(())
+    }
+
+    /// Like `walk`, but collects every entry, directories included, along
+    /// with its depth below the starting directory.
+    fn walk_entries(&self, dir: &Path, depth: usize, entries: &mut Vec<(PathBuf, usize)>, gitignore: &Option<Gitignore>) -> io::Result<()> {
+        if self.max_depth.is_some_and(|max| depth > max) {
+            return Ok(());
+        }
+        let gitignore = gitignore.as_ref().map(|g| g.with_dir(dir));
+
+        for entry in fs::read_dir(dir)? {
+            let entry = entry?;
+            let path = entry.path();
+            let is_dir = entry.file_type()?.is_dir();
+            if self.is_skipped(&path, is_dir, &gitignore) {
+                continue;
+            }
+
+            if is_dir {
+                self.walk_entries(&path, depth + 1, entries, &gitignore)?;
+            }
+            entries.push((path, depth));
+        }
+        Ok(())
+    }
+}
+
+fn is_glob(arg: &str) -> bool {
+    arg.contains(['*', '?'])
+}
+
+/// The directory before the first glob segment of `pattern`.
+fn glob_base(pattern: &str) -> PathBuf {
+    let base: Vec<&str> = pattern.split('/').take_while(|segment| !is_glob(segment)).collect();
+    match base.as_slice() {
+        [] => PathBuf::from("."),
+        [""] => PathBuf::from("/"),
+        segments => PathBuf::from(segments.join("/")),
+    }
+}
+
+pub fn is_yaml_file(path: &Path) -> bool {
+    matches!(path.extension().and_then(|e| e.to_str()), Some("yaml" | "yml"))
+}
@@ -0,0 +1,39 @@
+use std::fmt;
+use std::io;
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+#[derive(Debug)]
+pub enum Error {
+    Io { path: String, source: io::Error },
+    Write { path: String, source: io::Error },
+    Yaml { path: String, source: serde_yaml::Error },
+    NoMatches { pattern: String },
+    UnsupportedUrl { url: String },
+    /// A rule id in `--rules` or the config that no rule has; `origin`
+    /// says where it was given.
+    UnknownRule { id: String, origin: String },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io { path, source } => write!(f, "Failed to read {}: {}", path, source),
+            Error::Write { path, source } => write!(f, "Failed to write {}: {}", path, source),
+            Error::Yaml { path, source } => write!(f, "Failed to parse {}: {}", path, source),
+            Error::NoMatches { pattern } => write!(f, "No files match {}", pattern),
+            Error::UnsupportedUrl { url } => write!(f, "Fetching manifests from URLs is not supported: {}", url),
+            Error::UnknownRule { id, origin } => write!(f, "Unknown rule '{}' in {}", id, origin),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io { source, .. } | Error::Write { source, .. } => Some(source),
+            Error::Yaml { source, .. } => Some(source),
+            Error::NoMatches { .. } | Error::UnsupportedUrl { .. } | Error::UnknownRule { .. } => None,
+        }
+    }
+}
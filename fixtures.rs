@@ -0,0 +1,109 @@
+//! Synthetic manifest sets for measuring rule-engine throughput, shared by
+//! the criterion benches and the hidden `bench` command.
+
+/// Representative repository sizes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FixtureSize {
+    Small,
+    Medium,
+    Huge,
+}
+
+impl FixtureSize {
+    pub const ALL: [FixtureSize; 3] = [FixtureSize::Small, FixtureSize::Medium, FixtureSize::Huge];
+
+    pub fn parse(size: &str) -> Option<FixtureSize> {
+        FixtureSize::ALL.into_iter().find(|s| s.name() == size)
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            FixtureSize::Small => "small",
+            FixtureSize::Medium => "medium",
+            FixtureSize::Huge => "huge",
+        }
+    }
+
+    /// Number of applications in the set; each is five documents.
+    pub fn apps(self) -> usize {
+        match self {
+            FixtureSize::Small => 10,
+            FixtureSize::Medium => 200,
+            FixtureSize::Huge => 1000,
+        }
+    }
+}
+
+/// `apps` applications spread over a few namespaces, each a Deployment,
+/// Service, ConfigMap, HorizontalPodAutoscaler and PodDisruptionBudget, as
+/// one multi-document YAML string. Cross-resource rules see every document,
+/// so this exercises them the way a large repo does.
+pub fn manifests(apps: usize) -> String {
+    (0..apps).map(|i| app(i, ["web", "payments", "batch", "platform"][i % 4])).collect::<Vec<_>>().join("---\n")
+}
+
+fn app(i: usize, namespace: &str) -> String {
+    format!(
+        r#"apiVersion: apps/v1
+kind: Deployment
+metadata:
+  name: app-{i}
+  namespace: {namespace}
+  labels: {{app.kubernetes.io/name: app-{i}}}
+spec:
+  replicas: 3
+  selector: {{matchLabels: {{app: app-{i}}}}}
+  template:
+    metadata:
+      labels: {{app: app-{i}}}
+    spec:
+      containers:
+      - name: app
+        image: ghcr.io/example/app-{i}:1.{i}.0
+        ports: [{{name: http, containerPort: 8080}}]
+        envFrom: [{{configMapRef: {{name: app-{i}}}}}]
+        resources:
+          requests: {{cpu: 100m, memory: 128Mi}}
+          limits: {{memory: 256Mi}}
+        readinessProbe: {{httpGet: {{path: /ready, port: http}}}}
+        livenessProbe: {{httpGet: {{path: /live, port: http}}}}
+        securityContext: {{runAsNonRoot: true, readOnlyRootFilesystem: true, allowPrivilegeEscalation: false}}
+---
+apiVersion: v1
+kind: Service
+metadata:
+  name: app-{i}
+  namespace: {namespace}
+spec:
+  selector: {{app: app-{i}}}
+  ports: [{{port: 80, targetPort: http}}]
+---
+apiVersion: v1
+kind: ConfigMap
+metadata:
+  name: app-{i}
+  namespace: {namespace}
+data: {{LOG_LEVEL: info}}
+---
+apiVersion: autoscaling/v2
+kind: HorizontalPodAutoscaler
+metadata:
+  name: app-{i}
+  namespace: {namespace}
+spec:
+  scaleTargetRef: {{apiVersion: apps/v1, kind: Deployment, name: app-{i}}}
+  minReplicas: 3
+  maxReplicas: 10
+  metrics: [{{type: Resource, resource: {{name: cpu, target: {{type: Utilization, averageUtilization: 70}}}}}}]
+---
+apiVersion: policy/v1
+kind: PodDisruptionBudget
+metadata:
+  name: app-{i}
+  namespace: {namespace}
+spec:
+  minAvailable: 1
+  selector: {{matchLabels: {{app: app-{i}}}}}
+"#
+    )
+}
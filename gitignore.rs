@@ -0,0 +1,111 @@
+//! Minimal `.gitignore` support for file discovery: `#` comments, `!`
+//! negation, trailing `/` for directories, leading or inner `/` to anchor a
+//! pattern to its `.gitignore`, and `*`, `?`, `**` wildcards.
+
+use std::fs;
+use std::path::{self, Path, PathBuf};
+
+use crate::utils;
+
+#[derive(Debug, Clone)]
+struct Rule {
+    /// Directory of the `.gitignore` the rule came from (absolute).
+    base: PathBuf,
+    pattern: String,
+    negated: bool,
+    dir_only: bool,
+    anchored: bool,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Gitignore {
+    rules: Vec<Rule>,
+}
+
+impl Gitignore {
+    /// Rules inherited by `root` from the `.gitignore` files of its parent
+    /// directories, up to the enclosing repository root. `root`'s own file
+    /// is added by `with_dir` when the walk enters it.
+    pub fn for_root(root: &Path) -> Gitignore {
+        let mut gitignore = Gitignore::default();
+        let Ok(root) = path::absolute(root) else { return gitignore };
+        if root.join(".git").exists() {
+            return gitignore;
+        }
+
+        let mut parents = vec![];
+        for dir in root.ancestors().skip(1) {
+            parents.push(dir);
+            if dir.join(".git").exists() {
+                for dir in parents.into_iter().rev() {
+                    gitignore.load(dir);
+                }
+                break;
+            }
+        }
+        gitignore
+    }
+
+    /// These rules plus those from `dir/.gitignore`, for walking into `dir`.
+    pub fn with_dir(&self, dir: &Path) -> Gitignore {
+        let mut gitignore = self.clone();
+        if let Ok(dir) = path::absolute(dir) {
+            gitignore.load(&dir);
+        }
+        gitignore
+    }
+
+    pub fn is_ignored(&self, path: &Path, is_dir: bool) -> bool {
+        if path.file_name().is_some_and(|name| name == ".git") {
+            return true;
+        }
+        let Ok(path) = path::absolute(path) else { return false };
+
+        // Later rules win, so the last matching rule decides.
+        let mut ignored = false;
+        for rule in &self.rules {
+            if rule.dir_only && !is_dir {
+                continue;
+            }
+            let Ok(relative) = path.strip_prefix(&rule.base) else { continue };
+            let relative = relative.to_string_lossy();
+            let matches = if rule.anchored {
+                utils::glob_match(&rule.pattern, &relative)
+            } else {
+                let name = relative.rsplit('/').next().unwrap_or(&relative);
+                utils::glob_match(&rule.pattern, name)
+            };
+            if matches {
+                ignored = !rule.negated;
+            }
+        }
+        ignored
+    }
+
+    fn load(&mut self, dir: &Path) {
+        let Ok(contents) = fs::read_to_string(dir.join(".gitignore")) else { return };
+
+        for line in contents.lines() {
+            let line = line.trim_end();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let (negated, line) = match line.strip_prefix('!') {
+                Some(rest) => (true, rest),
+                None => (false, line),
+            };
+            let (dir_only, line) = match line.strip_suffix('/') {
+                Some(rest) => (true, rest),
+                None => (false, line),
+            };
+            let anchored = line.contains('/');
+            self.rules.push(Rule {
+                base: dir.to_path_buf(),
+                pattern: line.trim_start_matches('/').to_string(),
+                negated,
+                dir_only,
+                anchored,
+            });
+        }
+    }
+}
@@ -0,0 +1,21 @@
+//! Kubernetes manifest linting engine behind the `rustykube` CLI.
+//!
+//! Nothing in this crate prints or exits the process; callers get reports
+//! and errors back and decide how to present them.
+
+pub mod config;
+pub mod discovery;
+pub mod error;
+/// Synthetic manifests for the `bench` command and criterion benches; not
+/// part of the supported API.
+#[doc(hidden)]
+pub mod fixtures;
+pub mod gitignore;
+pub mod lint_rules;
+pub mod linter;
+pub mod quantity;
+pub mod reporter;
+pub mod source;
+pub mod utils;
+
+pub use error::{Error, Result};
@@ -0,0 +1,109 @@
+use serde_yaml::Value;
+
+use super::{LintRule, Violation};
+
+/// A served apiVersion of a kind that Kubernetes deprecated and removed.
+struct Deprecation {
+    api_version: &'static str,
+    kinds: &'static [&'static str],
+    deprecated_in: (u32, u32),
+    removed_in: (u32, u32),
+    /// `None` when the kind was dropped without a replacement.
+    replacement: Option<&'static str>,
+}
+
+const fn deprecation(
+    api_version: &'static str,
+    kinds: &'static [&'static str],
+    deprecated_in: (u32, u32),
+    removed_in: (u32, u32),
+    replacement: Option<&'static str>,
+) -> Deprecation {
+    Deprecation { api_version, kinds, deprecated_in, removed_in, replacement }
+}
+
+const WORKLOADS: &[&str] = &["Deployment", "DaemonSet", "ReplicaSet", "StatefulSet"];
+const RBAC: &[&str] = &["Role", "ClusterRole", "RoleBinding", "ClusterRoleBinding"];
+const WEBHOOKS: &[&str] = &["MutatingWebhookConfiguration", "ValidatingWebhookConfiguration"];
+const FLOW_CONTROL: &[&str] = &["FlowSchema", "PriorityLevelConfiguration"];
+
+/// Removed API versions, from the Kubernetes deprecated API migration guide.
+const DEPRECATIONS: &[Deprecation] = &[
+    deprecation("extensions/v1beta1", WORKLOADS, (1, 9), (1, 16), Some("apps/v1")),
+    deprecation("apps/v1beta1", WORKLOADS, (1, 9), (1, 16), Some("apps/v1")),
+    deprecation("apps/v1beta2", WORKLOADS, (1, 9), (1, 16), Some("apps/v1")),
+    deprecation("extensions/v1beta1", &["NetworkPolicy"], (1, 9), (1, 16), Some("networking.k8s.io/v1")),
+    deprecation("extensions/v1beta1", &["PodSecurityPolicy"], (1, 10), (1, 16), Some("policy/v1beta1")),
+    deprecation("extensions/v1beta1", &["Ingress"], (1, 14), (1, 22), Some("networking.k8s.io/v1")),
+    deprecation("networking.k8s.io/v1beta1", &["Ingress", "IngressClass"], (1, 19), (1, 22), Some("networking.k8s.io/v1")),
+    deprecation("rbac.authorization.k8s.io/v1beta1", RBAC, (1, 17), (1, 22), Some("rbac.authorization.k8s.io/v1")),
+    deprecation("apiextensions.k8s.io/v1beta1", &["CustomResourceDefinition"], (1, 16), (1, 22), Some("apiextensions.k8s.io/v1")),
+    deprecation("apiregistration.k8s.io/v1beta1", &["APIService"], (1, 19), (1, 22), Some("apiregistration.k8s.io/v1")),
+    deprecation("admissionregistration.k8s.io/v1beta1", WEBHOOKS, (1, 16), (1, 22), Some("admissionregistration.k8s.io/v1")),
+    deprecation("certificates.k8s.io/v1beta1", &["CertificateSigningRequest"], (1, 19), (1, 22), Some("certificates.k8s.io/v1")),
+    deprecation("coordination.k8s.io/v1beta1", &["Lease"], (1, 19), (1, 22), Some("coordination.k8s.io/v1")),
+    deprecation("scheduling.k8s.io/v1beta1", &["PriorityClass"], (1, 14), (1, 22), Some("scheduling.k8s.io/v1")),
+    deprecation(
+        "storage.k8s.io/v1beta1",
+        &["CSIDriver", "CSINode", "StorageClass", "VolumeAttachment"],
+        (1, 19),
+        (1, 22),
+        Some("storage.k8s.io/v1"),
+    ),
+    deprecation("batch/v1beta1", &["CronJob"], (1, 21), (1, 25), Some("batch/v1")),
+    deprecation("discovery.k8s.io/v1beta1", &["EndpointSlice"], (1, 21), (1, 25), Some("discovery.k8s.io/v1")),
+    deprecation("events.k8s.io/v1beta1", &["Event"], (1, 19), (1, 25), Some("events.k8s.io/v1")),
+    deprecation("autoscaling/v2beta1", &["HorizontalPodAutoscaler"], (1, 22), (1, 25), Some("autoscaling/v2")),
+    deprecation("policy/v1beta1", &["PodDisruptionBudget"], (1, 21), (1, 25), Some("policy/v1")),
+    deprecation("policy/v1beta1", &["PodSecurityPolicy"], (1, 21), (1, 25), None),
+    deprecation("node.k8s.io/v1beta1", &["RuntimeClass"], (1, 20), (1, 25), Some("node.k8s.io/v1")),
+    deprecation("autoscaling/v2beta2", &["HorizontalPodAutoscaler"], (1, 23), (1, 26), Some("autoscaling/v2")),
+    deprecation("flowcontrol.apiserver.k8s.io/v1beta1", FLOW_CONTROL, (1, 23), (1, 26), Some("flowcontrol.apiserver.k8s.io/v1")),
+    deprecation("storage.k8s.io/v1beta1", &["CSIStorageCapacity"], (1, 24), (1, 27), Some("storage.k8s.io/v1")),
+    deprecation("flowcontrol.apiserver.k8s.io/v1beta2", FLOW_CONTROL, (1, 26), (1, 29), Some("flowcontrol.apiserver.k8s.io/v1")),
+    deprecation("flowcontrol.apiserver.k8s.io/v1beta3", FLOW_CONTROL, (1, 29), (1, 32), Some("flowcontrol.apiserver.k8s.io/v1")),
+];
+
+/// Flags apiVersions that are deprecated or removed as of the target
+/// Kubernetes version. Without a target version every deprecated
+/// apiVersion is reported.
+pub struct DeprecatedApiRule {
+    version: Option<(u32, u32)>,
+}
+
+impl DeprecatedApiRule {
+    pub fn new(version: Option<(u32, u32)>) -> Self {
+        DeprecatedApiRule { version }
+    }
+}
+
+impl LintRule for DeprecatedApiRule {
+    fn id(&self) -> &'static str {
+        "deprecated-api"
+    }
+
+    fn check(&self, doc: &Value) -> Vec<Violation> {
+        let Some(api_version) = doc.get("apiVersion").and_then(Value::as_str) else { return vec![] };
+        let Some(kind) = doc.get("kind").and_then(Value::as_str) else { return vec![] };
+        let Some(deprecation) = DEPRECATIONS.iter().find(|d| d.api_version == api_version && d.kinds.contains(&kind)) else {
+            return vec![];
+        };
+        if self.version.is_some_and(|version| version < deprecation.deprecated_in) {
+            return vec![];
+        }
+
+        let (major, minor) = deprecation.removed_in;
+        let status = match self.version {
+            Some(version) if version < deprecation.removed_in => {
+                let (since_major, since_minor) = deprecation.deprecated_in;
+                format!("is deprecated since {}.{} and will be removed in {}.{}", since_major, since_minor, major, minor)
+            }
+            _ => format!("was removed in Kubernetes {}.{}", major, minor),
+        };
+        let advice = match deprecation.replacement {
+            Some(replacement) => format!("use {}", replacement),
+            None => "it has no replacement".to_string(),
+        };
+        vec![Violation::new(format!("{} {} {}; {}.", kind, api_version, status, advice), "apiVersion")]
+    }
+}
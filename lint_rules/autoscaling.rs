@@ -0,0 +1,266 @@
+use serde_yaml::Value;
+
+use super::{availability, is_kind, metadata_namespace, workload, LintRule, ResourceSet, Violation};
+
+/// HPA v2 metric types, with the field holding each type's settings and
+/// the target types it accepts.
+const METRIC_TYPES: &[(&str, &str, &[&str])] = &[
+    ("Resource", "resource", &["Utilization", "AverageValue"]),
+    ("ContainerResource", "containerResource", &["Utilization", "AverageValue"]),
+    ("Pods", "pods", &["AverageValue"]),
+    ("Object", "object", &["Value", "AverageValue"]),
+    ("External", "external", &["Value", "AverageValue"]),
+];
+
+/// KEDA polls more often than this at the cost of hammering the scaler's
+/// source.
+const MIN_POLLING_INTERVAL: i64 = 5;
+
+/// Validates HorizontalPodAutoscaler v2 metric specs, whose mistakes are
+/// accepted by the API server and only show up as an HPA that never scales.
+pub struct HpaMetricsRule;
+
+impl LintRule for HpaMetricsRule {
+    fn id(&self) -> &'static str {
+        "hpa-metrics"
+    }
+
+    fn targets(&self, doc: &Value) -> bool {
+        is_kind(doc, &["HorizontalPodAutoscaler"])
+    }
+
+    fn check(&self, doc: &Value) -> Vec<Violation> {
+        if doc.get("kind").and_then(Value::as_str) != Some("HorizontalPodAutoscaler") {
+            return vec![];
+        }
+        let Some(metrics) = doc.get("spec").and_then(|s| s.get("metrics")).and_then(Value::as_sequence) else { return vec![] };
+
+        let mut violations = vec![];
+        for (i, metric) in metrics.iter().enumerate() {
+            let path = format!("spec.metrics[{}]", i);
+            let metric_type = metric.get("type").and_then(Value::as_str).unwrap_or_default();
+            let Some((_, field, target_types)) = METRIC_TYPES.iter().find(|(name, _, _)| *name == metric_type) else {
+                violations.push(Violation::new(
+                    format!("Unknown metric type '{}'; use Resource, ContainerResource, Pods, Object or External.", metric_type),
+                    format!("{}.type", path),
+                ));
+                continue;
+            };
+            let Some(source) = metric.get(*field) else {
+                violations.push(Violation::new(
+                    format!("{} metric must set {}.", metric_type, field),
+                    format!("{}.{}", path, field),
+                ));
+                continue;
+            };
+            let path = format!("{}.{}", path, field);
+
+            match *field {
+                "resource" | "containerResource" => {
+                    let name = source.get("name").and_then(Value::as_str).unwrap_or_default();
+                    if !matches!(name, "cpu" | "memory") {
+                        violations.push(Violation::new(
+                            format!("Resource metric name '{}' is not cpu or memory.", name),
+                            format!("{}.name", path),
+                        ));
+                    }
+                    if *field == "containerResource" && source.get("container").is_none() {
+                        violations.push(Violation::new("ContainerResource metric must set container.", format!("{}.container", path)));
+                    }
+                }
+                _ => {
+                    if source.get("metric").and_then(|m| m.get("name")).and_then(Value::as_str).is_none() {
+                        violations.push(Violation::new(
+                            format!("{} metric must set metric.name.", metric_type),
+                            format!("{}.metric.name", path),
+                        ));
+                    }
+                    let described = source.get("describedObject");
+                    if *field == "object" && ["kind", "name"].iter().any(|key| described.and_then(|d| d.get(*key)).is_none()) {
+                        violations.push(Violation::new(
+                            "Object metric must set describedObject kind and name.",
+                            format!("{}.describedObject", path),
+                        ));
+                    }
+                }
+            }
+
+            let target = source.get("target");
+            let target_type = target.and_then(|t| t.get("type")).and_then(Value::as_str).unwrap_or_default();
+            let value_field = match target_type {
+                "Utilization" => "averageUtilization",
+                "AverageValue" => "averageValue",
+                "Value" => "value",
+                _ => "",
+            };
+            if !target_types.contains(&target_type) {
+                violations.push(Violation::new(
+                    format!("{} metric target type must be one of {}.", metric_type, target_types.join(", ")),
+                    format!("{}.target.type", path),
+                ));
+            } else if target.and_then(|t| t.get(value_field)).is_none() {
+                violations.push(Violation::new(
+                    format!("{} target must set {}.", target_type, value_field),
+                    format!("{}.target.{}", path, value_field),
+                ));
+            }
+        }
+        violations
+    }
+}
+
+/// Cross-checks HorizontalPodAutoscalers against their targets: the
+/// target must be in the input set and request every resource a
+/// Utilization metric measures, and a workload an HPA scales must not pin
+/// `spec.replicas`, which GitOps tools keep resetting.
+pub struct HpaTargetRule;
+
+impl LintRule for HpaTargetRule {
+    fn id(&self) -> &'static str {
+        "hpa-target"
+    }
+
+    fn targets(&self, doc: &Value) -> bool {
+        is_kind(doc, &["HorizontalPodAutoscaler"]) || workload::pod_spec(doc).is_some()
+    }
+
+    fn check(&self, doc: &Value) -> Vec<Violation> {
+        self.check_in_set(doc, &ResourceSet::default())
+    }
+
+    fn check_in_set(&self, doc: &Value, set: &ResourceSet<'_>) -> Vec<Violation> {
+        let Some(kind) = doc.get("kind").and_then(Value::as_str) else { return vec![] };
+        if kind != "HorizontalPodAutoscaler" {
+            if doc.get("spec").and_then(|s| s.get("replicas")).is_some() && availability::autoscaler_min_replicas(set, doc).is_some() {
+                return vec![Violation::new(
+                    format!("{} is scaled by a HorizontalPodAutoscaler but also sets spec.replicas; remove it so deploys don't reset the HPA.", kind),
+                    "spec.replicas",
+                )];
+            }
+            return vec![];
+        }
+
+        let target_ref = doc.get("spec").and_then(|s| s.get("scaleTargetRef"));
+        let Some(name) = target_ref.and_then(|t| t.get("name")).and_then(Value::as_str) else {
+            return vec![Violation::new("HorizontalPodAutoscaler must set scaleTargetRef.name.", "spec.scaleTargetRef.name")];
+        };
+        let target_kind = target_ref.and_then(|t| t.get("kind")).and_then(Value::as_str).unwrap_or_default();
+        let Some(target) = set.find(&[target_kind], name, metadata_namespace(doc)) else {
+            if set.is_empty() {
+                return vec![];
+            }
+            return vec![Violation::new(
+                format!("HorizontalPodAutoscaler targets {} '{}', which is not in the input set.", target_kind, name),
+                "spec.scaleTargetRef",
+            )];
+        };
+        let Some(pod) = workload::pod_spec(target) else { return vec![] };
+        let containers = pod.containers();
+
+        let mut violations = vec![];
+        let metrics = doc.get("spec").and_then(|s| s.get("metrics")).and_then(Value::as_sequence).into_iter().flatten();
+        for (i, metric) in metrics.enumerate() {
+            let (source, container_name) = match metric.get("type").and_then(Value::as_str) {
+                Some("Resource") => (metric.get("resource"), None),
+                Some("ContainerResource") => {
+                    let source = metric.get("containerResource");
+                    (source, source.and_then(|s| s.get("container")).and_then(Value::as_str))
+                }
+                _ => continue,
+            };
+            let Some(source) = source else { continue };
+            if source.get("target").and_then(|t| t.get("type")).and_then(Value::as_str) != Some("Utilization") {
+                continue;
+            }
+            let Some(resource) = source.get("name").and_then(Value::as_str) else { continue };
+
+            let missing: Vec<&str> = containers
+                .iter()
+                .filter(|c| container_name.is_none() || c.value.get("name").and_then(Value::as_str) == container_name)
+                .filter(|c| c.value.get("resources").and_then(|r| r.get("requests")).and_then(|r| r.get(resource)).is_none())
+                .filter_map(|c| c.value.get("name").and_then(Value::as_str))
+                .collect();
+            if !missing.is_empty() {
+                violations.push(Violation::new(
+                    format!(
+                        "{} utilization is measured against requests, but these containers of {} '{}' have no {} request: {}.",
+                        resource,
+                        target_kind,
+                        name,
+                        resource,
+                        missing.join(", ")
+                    ),
+                    format!("spec.metrics[{}]", i),
+                ));
+            }
+        }
+        violations
+    }
+}
+
+/// Validates KEDA ScaledObjects: the scale target must be in the input set,
+/// triggers must name a type and metadata, and polling must not be
+/// aggressive enough to hammer the trigger source.
+pub struct KedaScaledObjectRule;
+
+impl LintRule for KedaScaledObjectRule {
+    fn id(&self) -> &'static str {
+        "keda-scaled-object"
+    }
+
+    fn targets(&self, doc: &Value) -> bool {
+        is_kind(doc, &["ScaledObject"])
+    }
+
+    fn check(&self, doc: &Value) -> Vec<Violation> {
+        self.check_in_set(doc, &ResourceSet::default())
+    }
+
+    fn check_in_set(&self, doc: &Value, set: &ResourceSet<'_>) -> Vec<Violation> {
+        if doc.get("kind").and_then(Value::as_str) != Some("ScaledObject") {
+            return vec![];
+        }
+        let spec = doc.get("spec");
+        let mut violations = vec![];
+
+        let target = spec.and_then(|s| s.get("scaleTargetRef"));
+        match target.and_then(|t| t.get("name")).and_then(Value::as_str) {
+            Some(name) => {
+                let kind = target.and_then(|t| t.get("kind")).and_then(Value::as_str).unwrap_or("Deployment");
+                if !set.is_empty() && set.find(&[kind], name, metadata_namespace(doc)).is_none() {
+                    violations.push(Violation::new(
+                        format!("ScaledObject targets {} '{}', which is not in the input set.", kind, name),
+                        "spec.scaleTargetRef.name",
+                    ));
+                }
+            }
+            None => violations.push(Violation::new("ScaledObject must set scaleTargetRef.name.", "spec.scaleTargetRef.name")),
+        }
+
+        if let Some(interval) = spec.and_then(|s| s.get("pollingInterval")) {
+            if interval.as_i64().is_none_or(|seconds| seconds < MIN_POLLING_INTERVAL) {
+                violations.push(Violation::new(
+                    format!("pollingInterval must be at least {} seconds.", MIN_POLLING_INTERVAL),
+                    "spec.pollingInterval",
+                ));
+            }
+        }
+
+        match spec.and_then(|s| s.get("triggers")).and_then(Value::as_sequence) {
+            Some(triggers) if !triggers.is_empty() => {
+                for (i, trigger) in triggers.iter().enumerate() {
+                    for field in ["type", "metadata"] {
+                        if trigger.get(field).is_none() {
+                            violations.push(Violation::new(
+                                format!("Trigger must set {}.", field),
+                                format!("spec.triggers[{}].{}", i, field),
+                            ));
+                        }
+                    }
+                }
+            }
+            _ => violations.push(Violation::new("ScaledObject has no triggers.", "spec.triggers")),
+        }
+        violations
+    }
+}
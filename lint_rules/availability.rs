@@ -0,0 +1,160 @@
+use serde_yaml::Value;
+
+use super::{is_kind, metadata_name, metadata_namespace, same_namespace, selectors, workload, LintRule, ResourceSet, Violation};
+
+const REPLICATED_KINDS: &[&str] = &["Deployment", "StatefulSet", "Rollout"];
+
+/// For production clusters: Deployments, StatefulSets and Rollouts must run
+/// more than one replica, either directly or through an autoscaler's
+/// `minReplicas`.
+pub struct SingleReplicaRule {
+    production: bool,
+}
+
+impl SingleReplicaRule {
+    pub fn new(production: bool) -> Self {
+        SingleReplicaRule { production }
+    }
+}
+
+impl LintRule for SingleReplicaRule {
+    fn id(&self) -> &'static str {
+        "single-replica"
+    }
+
+    fn targets(&self, doc: &Value) -> bool {
+        self.production && is_kind(doc, REPLICATED_KINDS)
+    }
+
+    fn check(&self, doc: &Value) -> Vec<Violation> {
+        self.check_in_set(doc, &ResourceSet::default())
+    }
+
+    fn check_in_set(&self, doc: &Value, set: &ResourceSet<'_>) -> Vec<Violation> {
+        let Some(kind) = doc.get("kind").and_then(Value::as_str) else { return vec![] };
+        if !self.production || !REPLICATED_KINDS.contains(&kind) {
+            return vec![];
+        }
+        if autoscaler_min_replicas(set, doc).is_some_and(|min| min > 1) {
+            return vec![];
+        }
+
+        match doc.get("spec").and_then(|s| s.get("replicas")) {
+            // Kubernetes defaults to a single replica.
+            None => vec![Violation::new(
+                format!("{} does not set replicas and runs a single pod.", kind),
+                "spec.replicas",
+            )],
+            Some(replicas) if replicas.as_i64().is_some_and(|r| r <= 1) => vec![Violation::new(
+                format!("{} runs {} replica(s); a single pod failure takes it down.", kind, replicas.as_i64().unwrap_or_default()),
+                "spec.replicas",
+            )],
+            _ => vec![],
+        }
+    }
+}
+
+/// `minReplicas` of a HorizontalPodAutoscaler in `set` that scales `doc`.
+/// An autoscaler without `minReplicas` keeps at least one replica.
+pub fn autoscaler_min_replicas(set: &ResourceSet<'_>, doc: &Value) -> Option<i64> {
+    let kind = doc.get("kind").and_then(Value::as_str)?;
+    let name = metadata_name(doc)?;
+    let namespace = metadata_namespace(doc);
+
+    set.of_kind(&["HorizontalPodAutoscaler"])
+        .filter(|hpa| same_namespace(metadata_namespace(hpa), namespace))
+        .find(|hpa| {
+            let target = hpa.get("spec").and_then(|s| s.get("scaleTargetRef"));
+            target.and_then(|t| t.get("kind")).and_then(Value::as_str) == Some(kind)
+                && target.and_then(|t| t.get("name")).and_then(Value::as_str) == Some(name)
+        })
+        .map(|hpa| hpa.get("spec").and_then(|s| s.get("minReplicas")).and_then(Value::as_i64).unwrap_or(1))
+}
+
+/// Flags multi-replica Deployments, StatefulSets and Rollouts that no
+/// PodDisruptionBudget in the input set selects, so a node drain can evict
+/// every replica at once.
+pub struct PodDisruptionBudgetRule;
+
+impl LintRule for PodDisruptionBudgetRule {
+    fn id(&self) -> &'static str {
+        "pod-disruption-budget"
+    }
+
+    fn targets(&self, doc: &Value) -> bool {
+        is_kind(doc, REPLICATED_KINDS)
+    }
+
+    fn check(&self, doc: &Value) -> Vec<Violation> {
+        self.check_in_set(doc, &ResourceSet::default())
+    }
+
+    fn check_in_set(&self, doc: &Value, set: &ResourceSet<'_>) -> Vec<Violation> {
+        let Some(kind) = doc.get("kind").and_then(Value::as_str) else { return vec![] };
+        if !REPLICATED_KINDS.contains(&kind) || replicas(set, doc) <= 1 {
+            return vec![];
+        }
+        if selectors::pod_labels(doc).is_none() {
+            return vec![];
+        }
+
+        let covered = set.of_kind(&["PodDisruptionBudget"]).any(|pdb| {
+            pdb.get("spec").and_then(|s| s.get("selector")).is_some_and(|selector| selectors::selects_workload(pdb, selector, doc))
+        });
+        if covered {
+            return vec![];
+        }
+        vec![Violation::new(
+            format!("{} runs multiple replicas but no PodDisruptionBudget selects its pods.", kind),
+            "spec.template.metadata.labels",
+        )]
+    }
+}
+
+/// Desired replica count, counting an autoscaler's `minReplicas` and the
+/// Kubernetes default of one.
+pub fn replicas(set: &ResourceSet<'_>, doc: &Value) -> i64 {
+    let replicas = doc.get("spec").and_then(|s| s.get("replicas")).and_then(Value::as_i64).unwrap_or(1);
+    autoscaler_min_replicas(set, doc).map_or(replicas, |min| min.max(replicas))
+}
+
+/// Flags multi-replica workloads with neither pod anti-affinity nor
+/// topologySpreadConstraints, since the scheduler may then put every
+/// replica on the same node.
+pub struct ReplicaSpreadRule;
+
+impl LintRule for ReplicaSpreadRule {
+    fn id(&self) -> &'static str {
+        "replica-spread"
+    }
+
+    fn targets(&self, doc: &Value) -> bool {
+        is_kind(doc, REPLICATED_KINDS)
+    }
+
+    fn check(&self, doc: &Value) -> Vec<Violation> {
+        self.check_in_set(doc, &ResourceSet::default())
+    }
+
+    fn check_in_set(&self, doc: &Value, set: &ResourceSet<'_>) -> Vec<Violation> {
+        let Some(kind) = doc.get("kind").and_then(Value::as_str) else { return vec![] };
+        if !REPLICATED_KINDS.contains(&kind) || replicas(set, doc) <= 1 {
+            return vec![];
+        }
+        let Some(pod) = workload::pod_spec(doc) else { return vec![] };
+
+        let anti_affinity = pod.spec.get("affinity").and_then(|a| a.get("podAntiAffinity")).is_some();
+        let spread = pod
+            .spec
+            .get("topologySpreadConstraints")
+            .and_then(Value::as_sequence)
+            .is_some_and(|constraints| !constraints.is_empty());
+        if anti_affinity || spread {
+            return vec![];
+        }
+        vec![Violation::new(
+            format!("{} runs multiple replicas without pod anti-affinity or topologySpreadConstraints.", kind),
+            format!("{}.topologySpreadConstraints", pod.path),
+        )]
+    }
+}
@@ -0,0 +1,261 @@
+use serde_yaml::Value;
+
+use super::{is_kind, LintRule, Violation};
+
+/// History limits above this keep more finished Jobs and their pods around
+/// than anyone will look at.
+const MAX_HISTORY_LIMIT: i64 = 10;
+
+const MONTHS: &[&str] = &["JAN", "FEB", "MAR", "APR", "MAY", "JUN", "JUL", "AUG", "SEP", "OCT", "NOV", "DEC"];
+const WEEKDAYS: &[&str] = &["SUN", "MON", "TUE", "WED", "THU", "FRI", "SAT"];
+
+/// Flags CronJobs that leave scheduling behaviour to defaults that rarely
+/// fit: no `concurrencyPolicy` (runs may overlap), no
+/// `startingDeadlineSeconds` (missed runs pile up after downtime), and
+/// history limits high enough to flood the namespace with finished Jobs.
+pub struct CronJobSettingsRule;
+
+impl LintRule for CronJobSettingsRule {
+    fn id(&self) -> &'static str {
+        "cronjob-settings"
+    }
+
+    fn targets(&self, doc: &Value) -> bool {
+        is_kind(doc, &["CronJob"])
+    }
+
+    fn check(&self, doc: &Value) -> Vec<Violation> {
+        if doc.get("kind").and_then(Value::as_str) != Some("CronJob") {
+            return vec![];
+        }
+        let spec = doc.get("spec");
+        let field = |key: &str| spec.and_then(|s| s.get(key));
+
+        let mut violations = vec![];
+        if field("concurrencyPolicy").is_none() {
+            violations.push(Violation::new(
+                "CronJob does not set concurrencyPolicy; runs may overlap (Allow). Set Forbid or Replace.",
+                "spec.concurrencyPolicy",
+            ));
+        }
+        if field("startingDeadlineSeconds").is_none() {
+            violations.push(Violation::new(
+                "CronJob does not set startingDeadlineSeconds; missed runs start late or stop scheduling after downtime.",
+                "spec.startingDeadlineSeconds",
+            ));
+        }
+        for key in ["successfulJobsHistoryLimit", "failedJobsHistoryLimit"] {
+            let Some(limit) = field(key).and_then(Value::as_i64) else { continue };
+            if limit > MAX_HISTORY_LIMIT {
+                violations.push(Violation::new(
+                    format!("CronJob {} is {}; keep at most {} finished Jobs.", key, limit, MAX_HISTORY_LIMIT),
+                    format!("spec.{}", key),
+                ));
+            }
+        }
+        violations
+    }
+}
+
+/// Flags Jobs, and CronJob job templates, that rely on defaults for retries
+/// and cleanup: no `backoffLimit` (six retries), no `activeDeadlineSeconds`
+/// (a hung Job runs forever), and for plain Jobs no
+/// `ttlSecondsAfterFinished` (finished Jobs are never deleted). CronJobs
+/// clean up through their history limits instead.
+pub struct JobSettingsRule;
+
+impl LintRule for JobSettingsRule {
+    fn id(&self) -> &'static str {
+        "job-settings"
+    }
+
+    fn targets(&self, doc: &Value) -> bool {
+        is_kind(doc, &["Job", "CronJob"])
+    }
+
+    fn check(&self, doc: &Value) -> Vec<Violation> {
+        let (spec, path, history_limited) = match doc.get("kind").and_then(Value::as_str) {
+            Some("Job") => (doc.get("spec"), "spec", false),
+            Some("CronJob") => {
+                (doc.get("spec").and_then(|s| s.get("jobTemplate")).and_then(|t| t.get("spec")), "spec.jobTemplate.spec", true)
+            }
+            _ => return vec![],
+        };
+        let mut required = vec![
+            ("backoffLimit", "failing pods are retried 6 times"),
+            ("activeDeadlineSeconds", "a hung Job runs forever"),
+        ];
+        if !history_limited {
+            required.push(("ttlSecondsAfterFinished", "finished Jobs and their pods are never cleaned up"));
+        }
+
+        required
+            .into_iter()
+            .filter(|(key, _)| spec.and_then(|s| s.get(*key)).is_none())
+            .map(|(key, consequence)| Violation::new(format!("Job does not set {}; {}.", key, consequence), format!("{}.{}", path, key)))
+            .collect()
+    }
+}
+
+/// Flags CronJob schedules that are not valid cron expressions, and time
+/// zones embedded in the schedule instead of `spec.timeZone`.
+pub struct CronJobScheduleRule;
+
+impl LintRule for CronJobScheduleRule {
+    fn id(&self) -> &'static str {
+        "cronjob-schedule"
+    }
+
+    fn targets(&self, doc: &Value) -> bool {
+        is_kind(doc, &["CronJob"])
+    }
+
+    fn check(&self, doc: &Value) -> Vec<Violation> {
+        if doc.get("kind").and_then(Value::as_str) != Some("CronJob") {
+            return vec![];
+        }
+        let Some(schedule) = doc.get("spec").and_then(|s| s.get("schedule")).and_then(Value::as_str) else {
+            return vec![Violation::new("CronJob has no schedule.", "spec.schedule")];
+        };
+        if schedule.starts_with("TZ=") || schedule.starts_with("CRON_TZ=") {
+            return vec![Violation::new(
+                format!("CronJob schedule '{}' embeds a time zone, which Kubernetes rejects; use spec.timeZone.", schedule),
+                "spec.schedule",
+            )];
+        }
+        match schedule_error(schedule) {
+            Some(error) => vec![Violation::new(format!("CronJob schedule '{}' is invalid: {}.", schedule, error), "spec.schedule")],
+            None => vec![],
+        }
+    }
+}
+
+/// Why `schedule` is not a valid five-field cron expression or `@` macro.
+fn schedule_error(schedule: &str) -> Option<String> {
+    let schedule = schedule.trim();
+    if let Some(descriptor) = schedule.strip_prefix('@') {
+        return match descriptor {
+            "yearly" | "annually" | "monthly" | "weekly" | "daily" | "midnight" | "hourly" => None,
+            _ if descriptor.starts_with("every ") => match descriptor["every ".len()..].trim() {
+                duration if is_duration(duration) => None,
+                duration => Some(format!("invalid @every duration '{}'", duration)),
+            },
+            _ => Some(format!("unknown descriptor '@{}'", descriptor)),
+        };
+    }
+
+    let fields: Vec<&str> = schedule.split_whitespace().collect();
+    if fields.len() != 5 {
+        return Some(format!("expected 5 fields, found {}", fields.len()));
+    }
+    let specs: [(&str, u32, u32, &[&str]); 5] =
+        [("minute", 0, 59, &[]), ("hour", 0, 23, &[]), ("day of month", 1, 31, &[]), ("month", 1, 12, MONTHS), ("day of week", 0, 6, WEEKDAYS)];
+    fields
+        .iter()
+        .zip(specs)
+        .find_map(|(field, (name, min, max, names))| {
+            (!field.split(',').all(|item| valid_item(item, min, max, names))).then(|| format!("bad {} field '{}'", name, field))
+        })
+}
+
+/// Whether `duration` is a Go duration such as `1h30m`, `90s` or `1.5h`, as
+/// `@every` takes.
+fn is_duration(duration: &str) -> bool {
+    if duration == "0" {
+        return true;
+    }
+    let mut rest = duration;
+    while !rest.is_empty() {
+        let number = rest.find(|c: char| !(c.is_ascii_digit() || c == '.')).unwrap_or(rest.len());
+        let (value, after) = rest.split_at(number);
+        if value.parse::<f64>().is_err() {
+            return false;
+        }
+        let Some(unit) = ["ns", "us", "µs", "μs", "ms", "s", "m", "h"].iter().find(|unit| after.starts_with(**unit)) else {
+            return false;
+        };
+        rest = &after[unit.len()..];
+    }
+    !duration.is_empty()
+}
+
+/// Whether a list item such as `*`, `5`, `1-5`, `*/15` or `MON-FRI` lies
+/// within `min..=max`.
+fn valid_item(item: &str, min: u32, max: u32, names: &[&str]) -> bool {
+    let (range, step) = match item.split_once('/') {
+        Some((range, step)) => (range, Some(step)),
+        None => (item, None),
+    };
+    if step.is_some_and(|step| step.parse::<u32>().map_or(true, |step| step == 0)) {
+        return false;
+    }
+    let value = |value: &str| -> Option<u32> {
+        let number = match names.iter().position(|name| name.eq_ignore_ascii_case(value)) {
+            // Month names start at 1, weekday names at 0.
+            Some(index) => index as u32 + min,
+            None => value.parse().ok()?,
+        };
+        (min..=max).contains(&number).then_some(number)
+    };
+    match range {
+        "*" | "?" => true,
+        _ => match range.split_once('-') {
+            Some((start, end)) => matches!((value(start), value(end)), (Some(start), Some(end)) if start <= end),
+            None => value(range).is_some(),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::schedule_error;
+
+    #[test]
+    fn accepts_valid_schedules() {
+        for schedule in [
+            "* * * * *",
+            "0 0 * * *",
+            "*/15 * * * *",
+            "0 9-17 * * 1-5",
+            "0 9-17/2 * * MON-FRI",
+            "30 2 1,15 * *",
+            "0 0 1 jan,jul *",
+            "0 0 ? * SUN",
+            "0 0 * * 6",
+            "@daily",
+            "@every 1h30m",
+            "@every 90s",
+            "@every 1.5h",
+        ] {
+            assert_eq!(schedule_error(schedule), None, "{:?} should be valid", schedule);
+        }
+    }
+
+    #[test]
+    fn rejects_out_of_range_values() {
+        for schedule in ["60 * * * *", "* 24 * * *", "* * 0 * *", "* * 32 * *", "* * * 13 * ", "* * * * 7", "5-1 * * * *"] {
+            assert!(schedule_error(schedule).is_some(), "{:?} should be invalid", schedule);
+        }
+    }
+
+    #[test]
+    fn rejects_bad_steps_and_names() {
+        for schedule in ["*/0 * * * *", "*/x * * * *", "* * * FOO *", "* * * * MONDAY", "1-/2 * * * *"] {
+            assert!(schedule_error(schedule).is_some(), "{:?} should be invalid", schedule);
+        }
+    }
+
+    #[test]
+    fn rejects_wrong_field_counts() {
+        assert_eq!(schedule_error("* * * *"), Some("expected 5 fields, found 4".to_string()));
+        assert_eq!(schedule_error("0 * * * * *"), Some("expected 5 fields, found 6".to_string()));
+        assert_eq!(schedule_error(""), Some("expected 5 fields, found 0".to_string()));
+    }
+
+    #[test]
+    fn rejects_bad_descriptors() {
+        for schedule in ["@often", "@every", "@every soon", "@every 5", "@every 1x", "@every h"] {
+            assert!(schedule_error(schedule).is_some(), "{:?} should be invalid", schedule);
+        }
+    }
+}
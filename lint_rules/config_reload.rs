@@ -0,0 +1,103 @@
+use serde_yaml::Value;
+
+use super::{is_kind, workload, LintRule, Violation};
+
+/// Flags Deployments, StatefulSets, DaemonSets and Argo Rollouts that consume ConfigMaps or
+/// Secrets but have nothing to restart their pods when those change: no
+/// `checksum/*` pod template annotation, and none of the configured reloader
+/// annotations (e.g. `reloader.stakater.com/auto`) on the workload.
+pub struct ConfigReloadRule {
+    reloader_annotations: Vec<String>,
+}
+
+impl ConfigReloadRule {
+    pub fn new(reloader_annotations: Vec<String>) -> Self {
+        ConfigReloadRule { reloader_annotations }
+    }
+}
+
+impl LintRule for ConfigReloadRule {
+    fn id(&self) -> &'static str {
+        "config-reload"
+    }
+
+    fn targets(&self, doc: &Value) -> bool {
+        is_kind(doc, &["Deployment", "StatefulSet", "DaemonSet", "Rollout"])
+    }
+
+    fn check(&self, doc: &Value) -> Vec<Violation> {
+        if !matches!(doc.get("kind").and_then(Value::as_str), Some("Deployment" | "StatefulSet" | "DaemonSet" | "Rollout")) {
+            return vec![];
+        }
+        let Some(pod) = workload::pod_spec(doc) else { return vec![] };
+
+        let references = config_references(&pod);
+        if references.is_empty() {
+            return vec![];
+        }
+
+        let annotations = |value: Option<&Value>| {
+            value
+                .and_then(|v| v.get("metadata"))
+                .and_then(|m| m.get("annotations"))
+                .and_then(Value::as_mapping)
+                .into_iter()
+                .flatten()
+                .filter_map(|(key, _)| key.as_str())
+                .map(str::to_string)
+                .collect::<Vec<_>>()
+        };
+        let template_annotations = annotations(doc.get("spec").and_then(|s| s.get("template")));
+        let workload_annotations = annotations(Some(doc));
+
+        let has_checksum = template_annotations.iter().any(|key| key.starts_with("checksum/"));
+        let has_reloader = workload_annotations.iter().any(|key| self.reloader_annotations.contains(key));
+        if has_checksum || has_reloader {
+            return vec![];
+        }
+
+        vec![Violation::new(
+            format!(
+                "Pods consume {} but have no checksum/* annotation or reloader annotation to restart them when those change.",
+                references.join(", ")
+            ),
+            "spec.template.metadata.annotations",
+        )]
+    }
+}
+
+/// ConfigMaps and Secrets the pod mounts or reads into env, e.g.
+/// `ConfigMap 'app-config'`, each listed once.
+fn config_references(pod: &workload::PodSpec<'_>) -> Vec<String> {
+    let mut references = vec![];
+    let mut add = |kind: &str, name: Option<&Value>| {
+        let Some(name) = name.and_then(Value::as_str) else { return };
+        let reference = format!("{} '{}'", kind, name);
+        if !references.contains(&reference) {
+            references.push(reference);
+        }
+    };
+
+    for volume in pod.spec.get("volumes").and_then(Value::as_sequence).into_iter().flatten() {
+        add("ConfigMap", volume.get("configMap").and_then(|c| c.get("name")));
+        add("Secret", volume.get("secret").and_then(|s| s.get("secretName")));
+        let sources = volume.get("projected").and_then(|p| p.get("sources")).and_then(Value::as_sequence);
+        for source in sources.into_iter().flatten() {
+            add("ConfigMap", source.get("configMap").and_then(|c| c.get("name")));
+            add("Secret", source.get("secret").and_then(|s| s.get("name")));
+        }
+    }
+
+    for container in pod.all_containers() {
+        for env_from in container.value.get("envFrom").and_then(Value::as_sequence).into_iter().flatten() {
+            add("ConfigMap", env_from.get("configMapRef").and_then(|c| c.get("name")));
+            add("Secret", env_from.get("secretRef").and_then(|s| s.get("name")));
+        }
+        for env in container.value.get("env").and_then(Value::as_sequence).into_iter().flatten() {
+            let value_from = env.get("valueFrom");
+            add("ConfigMap", value_from.and_then(|v| v.get("configMapKeyRef")).and_then(|c| c.get("name")));
+            add("Secret", value_from.and_then(|v| v.get("secretKeyRef")).and_then(|s| s.get("name")));
+        }
+    }
+    references
+}
@@ -0,0 +1,159 @@
+use serde_yaml::Value;
+
+use super::{workload, LintRule, Violation};
+use crate::quantity::Quantity;
+
+/// `fieldRef` paths allowed in environment variables. Labels and annotations
+/// must be selected by key.
+const ENV_FIELD_PATHS: &[&str] = &[
+    "metadata.name",
+    "metadata.namespace",
+    "metadata.uid",
+    "spec.nodeName",
+    "spec.serviceAccountName",
+    "status.hostIP",
+    "status.hostIPs",
+    "status.podIP",
+    "status.podIPs",
+];
+
+/// `fieldRef` paths allowed in downwardAPI volumes, which can also expose
+/// all labels or annotations at once but no spec or status fields.
+const VOLUME_FIELD_PATHS: &[&str] = &["metadata.name", "metadata.namespace", "metadata.uid", "metadata.labels", "metadata.annotations"];
+
+/// Divisors the API server accepts, compared as parsed quantities so
+/// equivalents such as `1000m` or `0.001` pass too.
+const CPU_DIVISORS: &[&str] = &["1", "1m"];
+const SIZE_DIVISORS: &[&str] = &["1", "1k", "1M", "1G", "1T", "1P", "1E", "1Ki", "1Mi", "1Gi", "1Ti", "1Pi", "1Ei"];
+
+/// Validates downward API selectors in env vars and volumes, which the API
+/// server otherwise only rejects at admission time.
+pub struct DownwardApiRule;
+
+impl LintRule for DownwardApiRule {
+    fn id(&self) -> &'static str {
+        "downward-api"
+    }
+
+    fn targets(&self, doc: &Value) -> bool {
+        workload::pod_spec(doc).is_some()
+    }
+
+    fn check(&self, doc: &Value) -> Vec<Violation> {
+        let Some(pod) = workload::pod_spec(doc) else { return vec![] };
+        let containers = pod.all_containers();
+        let names: Vec<&str> = containers.iter().filter_map(|c| c.value.get("name").and_then(Value::as_str)).collect();
+
+        let mut violations = vec![];
+        for container in &containers {
+            let Some(env) = container.value.get("env").and_then(Value::as_sequence) else { continue };
+            for (i, var) in env.iter().enumerate() {
+                let Some(value_from) = var.get("valueFrom") else { continue };
+                let path = format!("{}.env[{}].valueFrom", container.path, i);
+                check_refs(value_from, &path, ENV_FIELD_PATHS, &names, false, &mut violations);
+            }
+        }
+
+        let volumes = pod.spec.get("volumes").and_then(Value::as_sequence).into_iter().flatten();
+        for (i, volume) in volumes.enumerate() {
+            let volume_path = format!("{}.volumes[{}]", pod.path, i);
+            let mut sources = vec![];
+            if let Some(downward) = volume.get("downwardAPI") {
+                sources.push((downward, format!("{}.downwardAPI", volume_path)));
+            }
+            let projected = volume.get("projected").and_then(|p| p.get("sources")).and_then(Value::as_sequence);
+            for (j, source) in projected.into_iter().flatten().enumerate() {
+                if let Some(downward) = source.get("downwardAPI") {
+                    sources.push((downward, format!("{}.projected.sources[{}].downwardAPI", volume_path, j)));
+                }
+            }
+
+            for (downward, path) in sources {
+                let items = downward.get("items").and_then(Value::as_sequence).into_iter().flatten();
+                for (j, item) in items.enumerate() {
+                    let path = format!("{}.items[{}]", path, j);
+                    check_refs(item, &path, VOLUME_FIELD_PATHS, &names, true, &mut violations);
+                }
+            }
+        }
+        violations
+    }
+}
+
+/// Checks the `fieldRef` and `resourceFieldRef` of an env `valueFrom` or a
+/// downwardAPI volume item at `path`.
+fn check_refs(
+    value: &Value,
+    path: &str,
+    field_paths: &[&str],
+    containers: &[&str],
+    requires_container: bool,
+    violations: &mut Vec<Violation>,
+) {
+    if let Some(field_path) = value.get("fieldRef").and_then(|f| f.get("fieldPath")).and_then(Value::as_str) {
+        if !field_paths.contains(&field_path) && !is_keyed_metadata(field_path) {
+            violations.push(Violation::new(
+                format!("fieldRef path '{}' is not a valid downward API field.", field_path),
+                format!("{}.fieldRef.fieldPath", path),
+            ));
+        }
+    }
+
+    let Some(resource_ref) = value.get("resourceFieldRef") else { return };
+    match resource_ref.get("containerName").and_then(Value::as_str) {
+        Some(name) if !containers.contains(&name) => violations.push(Violation::new(
+            format!("resourceFieldRef refers to unknown container '{}'.", name),
+            format!("{}.resourceFieldRef.containerName", path),
+        )),
+        None if requires_container => violations.push(Violation::new(
+            "resourceFieldRef in a volume must set containerName.",
+            format!("{}.resourceFieldRef.containerName", path),
+        )),
+        _ => {}
+    }
+
+    let Some(resource) = resource_ref.get("resource").and_then(Value::as_str) else { return };
+    let Some(name) = resource.strip_prefix("limits.").or_else(|| resource.strip_prefix("requests.")) else {
+        violations.push(Violation::new(
+            format!("resourceFieldRef resource '{}' is not a valid container resource.", resource),
+            format!("{}.resourceFieldRef.resource", path),
+        ));
+        return;
+    };
+    let divisors = match name {
+        "cpu" => CPU_DIVISORS,
+        "memory" | "ephemeral-storage" => SIZE_DIVISORS,
+        _ if name.starts_with("hugepages-") => SIZE_DIVISORS,
+        _ => {
+            violations.push(Violation::new(
+                format!("resourceFieldRef resource '{}' is not a valid container resource.", resource),
+                format!("{}.resourceFieldRef.resource", path),
+            ));
+            return;
+        }
+    };
+
+    let divisor = match resource_ref.get("divisor") {
+        Some(Value::Number(number)) => number.to_string(),
+        Some(Value::String(divisor)) => divisor.clone(),
+        _ => return,
+    };
+    let valid = Quantity::parse(&divisor)
+        .is_some_and(|quantity| divisors.iter().any(|allowed| Quantity::parse(allowed) == Some(quantity)));
+    if !valid {
+        violations.push(Violation::new(
+            format!("Divisor '{}' is not valid for {}; use one of {}.", divisor, resource, divisors.join(", ")),
+            format!("{}.resourceFieldRef.divisor", path),
+        ));
+    }
+}
+
+/// `metadata.labels['key']` or `metadata.annotations['key']`.
+fn is_keyed_metadata(field_path: &str) -> bool {
+    ["metadata.labels['", "metadata.annotations['"].iter().any(|prefix| {
+        field_path
+            .strip_prefix(prefix)
+            .and_then(|rest| rest.strip_suffix("']"))
+            .is_some_and(|key| !key.is_empty())
+    })
+}
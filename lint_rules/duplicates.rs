@@ -0,0 +1,36 @@
+use serde_yaml::Value;
+
+use super::{metadata_name, metadata_namespace, LintRule, ResourceSet, Violation};
+
+/// Flags resources defined more than once across the input set, by kind,
+/// namespace and name; an unset namespace only matches another unset one.
+/// Whichever copy is applied last silently wins, so each copy is reported
+/// along with where the others are.
+pub struct DuplicateResourceRule;
+
+impl LintRule for DuplicateResourceRule {
+    fn id(&self) -> &'static str {
+        "duplicate-resource"
+    }
+
+    fn check(&self, doc: &Value) -> Vec<Violation> {
+        self.check_in_set(doc, &ResourceSet::default())
+    }
+
+    fn check_in_set(&self, doc: &Value, set: &ResourceSet<'_>) -> Vec<Violation> {
+        let Some(kind) = doc.get("kind").and_then(Value::as_str) else { return vec![] };
+        let Some(name) = metadata_name(doc) else { return vec![] };
+
+        set.named(kind, name, metadata_namespace(doc))
+            .into_iter()
+            .filter(|other| !std::ptr::eq(*other, doc))
+            .map(|other| {
+                let location = match set.origin(other) {
+                    Some(origin) => format!("{} (document {})", origin.path, origin.index + 1),
+                    None => "another document".to_string(),
+                };
+                Violation::new(format!("{} '{}' is also defined in {}.", kind, name, location), "metadata.name")
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,69 @@
+use serde_yaml::Value;
+
+use super::{metadata_namespace, workload, LintRule, ResourceSet, Violation};
+
+/// Flags containers that set the same env var twice, where Kubernetes
+/// silently keeps the last value, and env vars that shadow a key of an
+/// `envFrom` ConfigMap or Secret in the input set. Shadowing is sometimes
+/// intended, so it is only a warning.
+pub struct DuplicateEnvRule;
+
+impl LintRule for DuplicateEnvRule {
+    fn id(&self) -> &'static str {
+        "duplicate-env"
+    }
+
+    fn targets(&self, doc: &Value) -> bool {
+        workload::pod_spec(doc).is_some()
+    }
+
+    fn check(&self, doc: &Value) -> Vec<Violation> {
+        self.check_in_set(doc, &ResourceSet::default())
+    }
+
+    fn check_in_set(&self, doc: &Value, set: &ResourceSet<'_>) -> Vec<Violation> {
+        let Some(pod) = workload::pod_spec(doc) else { return vec![] };
+
+        let mut violations = vec![];
+        for container in pod.all_containers() {
+            let env = container.value.get("env").and_then(Value::as_sequence).into_iter().flatten();
+            let names: Vec<Option<&str>> = env.map(|var| var.get("name").and_then(Value::as_str)).collect();
+            for (i, name) in names.iter().enumerate() {
+                let Some(name) = name else { continue };
+                let field = format!("{}.env[{}].name", container.path, i);
+                if names[..i].contains(&Some(name)) {
+                    violations.push(Violation::new(
+                        format!("{} sets env var '{}' more than once; only the last value is used.", container.label(), name),
+                        field,
+                    ));
+                } else if let Some(source) = env_from_source(container.value, name, metadata_namespace(doc), set) {
+                    violations.push(Violation::warning(
+                        format!("{} env var '{}' overrides the same key from envFrom {}.", container.label(), name, source),
+                        field,
+                    ));
+                }
+            }
+        }
+        violations
+    }
+}
+
+/// The `envFrom` source, e.g. "ConfigMap 'app'", providing env var `name`,
+/// when that ConfigMap or Secret is in the input set.
+fn env_from_source(container: &Value, name: &str, namespace: Option<&str>, set: &ResourceSet<'_>) -> Option<String> {
+    let mut env_from = container.get("envFrom").and_then(Value::as_sequence).into_iter().flatten();
+    env_from.find_map(|source| {
+        let (kind, reference, sections): (_, _, &[&str]) = match (source.get("configMapRef"), source.get("secretRef")) {
+            (Some(reference), _) => ("ConfigMap", reference, &["data", "binaryData"]),
+            (_, Some(reference)) => ("Secret", reference, &["data", "stringData"]),
+            _ => return None,
+        };
+        let key = name.strip_prefix(source.get("prefix").and_then(Value::as_str).unwrap_or_default())?;
+        let resource_name = reference.get("name").and_then(Value::as_str)?;
+        let resource = set.find(&[kind], resource_name, namespace)?;
+        sections
+            .iter()
+            .any(|section| resource.get(*section).and_then(|data| data.get(key)).is_some())
+            .then(|| format!("{} '{}'", kind, resource_name))
+    })
+}
@@ -1,43 +1,200 @@
 use serde_yaml::Value;
 
-use super::LintRule;
+use super::{workload, LintRule, Violation};
+use crate::utils;
 
 pub struct LivenessProbeRule;
 
 impl LintRule for LivenessProbeRule {
-    fn check(&self, doc: &Value) -> Option<String> {
-        let containers = doc
-            .get("spec")?
-            .get("template")?
-            .get("spec")?
-            .get("containers")?
-            .as_sequence()?;
-
-        for container in containers {
-            if container.get("livenessProbe").is_none() {
-                return Some("Container is missing livenessProbe.".to_string());
+    fn id(&self) -> &'static str {
+        "liveness-probe"
+    }
+
+    fn targets(&self, doc: &Value) -> bool {
+        workload::pod_spec(doc).is_some_and(|pod| !pod.is_batch())
+    }
+
+    fn check(&self, doc: &Value) -> Vec<Violation> {
+        let Some(pod) = workload::pod_spec(doc).filter(|pod| !pod.is_batch()) else { return vec![] };
+
+        let mut violations = vec![];
+        for container in pod.all_containers().into_iter().filter(|c| c.is_long_running()) {
+            if container.value.get("livenessProbe").is_none() {
+                violations.push(Violation::new(
+                    format!("{} is missing livenessProbe.", container.label()),
+                    format!("{}.livenessProbe", container.path),
+                ));
             }
         }
-        None
+        violations
     }
 }
 
 pub struct ReadinessProbeRule;
 
 impl LintRule for ReadinessProbeRule {
-    fn check(&self, doc: &Value) -> Option<String> {
-        let containers = doc
-            .get("spec")?
-            .get("template")?
-            .get("spec")?
-            .get("containers")?
-            .as_sequence()?;
-
-        for container in containers {
-            if container.get("readinessProbe").is_none() {
-                return Some("Container is missing readinessProbe.".to_string())
+    fn id(&self) -> &'static str {
+        "readiness-probe"
+    }
+
+    fn targets(&self, doc: &Value) -> bool {
+        workload::pod_spec(doc).is_some_and(|pod| !pod.is_batch())
+    }
+
+    fn check(&self, doc: &Value) -> Vec<Violation> {
+        let Some(pod) = workload::pod_spec(doc).filter(|pod| !pod.is_batch()) else { return vec![] };
+
+        let mut violations = vec![];
+        for container in pod.all_containers().into_iter().filter(|c| c.is_long_running()) {
+            if container.value.get("readinessProbe").is_none() {
+                violations.push(Violation::new(
+                    format!("{} is missing readinessProbe.", container.label()),
+                    format!("{}.readinessProbe", container.path),
+                ));
+            }
+        }
+        violations
+    }
+}
+
+/// Fields holding a probe's handler; two probes with equal handlers check
+/// the same endpoint.
+const PROBE_HANDLERS: &[&str] = &["httpGet", "tcpSocket", "grpc", "exec"];
+
+/// Flags probe settings that defeat the probe: liveness and readiness on
+/// the same endpoint, timeouts that outlast the period, single-failure
+/// liveness restarts, and no initial delay on images known to start slowly.
+pub struct ProbeSanityRule {
+    slow_start_images: Vec<String>,
+}
+
+impl ProbeSanityRule {
+    pub fn new(slow_start_images: Vec<String>) -> Self {
+        ProbeSanityRule { slow_start_images }
+    }
+
+    fn is_slow_start(&self, image: &str) -> bool {
+        // Match on the image name alone, e.g. `elasticsearch` for
+        // `docker.elastic.co/elasticsearch/elasticsearch:8.13.0`.
+        let name = image.rsplit('/').next().unwrap_or(image);
+        let name = name.split(['@', ':']).next().unwrap_or(name);
+        self.slow_start_images.iter().any(|pattern| utils::glob_match(pattern, name))
+    }
+}
+
+impl LintRule for ProbeSanityRule {
+    fn id(&self) -> &'static str {
+        "probe-sanity"
+    }
+
+    fn targets(&self, doc: &Value) -> bool {
+        workload::pod_spec(doc).is_some_and(|pod| !pod.is_batch())
+    }
+
+    fn check(&self, doc: &Value) -> Vec<Violation> {
+        let Some(pod) = workload::pod_spec(doc).filter(|pod| !pod.is_batch()) else { return vec![] };
+
+        let mut violations = vec![];
+        for container in pod.all_containers().into_iter().filter(|c| c.is_long_running()) {
+            let liveness = container.value.get("livenessProbe");
+            let readiness = container.value.get("readinessProbe");
+
+            if let (Some(liveness), Some(readiness)) = (liveness, readiness) {
+                let same_handler = PROBE_HANDLERS
+                    .iter()
+                    .any(|handler| liveness.get(*handler).is_some() && liveness.get(*handler) == readiness.get(*handler));
+                if same_handler {
+                    violations.push(Violation::new(
+                        format!("{} liveness and readiness probes check the same endpoint.", container.label()),
+                        format!("{}.livenessProbe", container.path),
+                    ));
+                }
+            }
+
+            for probe_name in ["livenessProbe", "readinessProbe", "startupProbe"] {
+                let Some(probe) = container.value.get(probe_name) else { continue };
+                // Kubernetes defaults: timeoutSeconds 1, periodSeconds 10.
+                let timeout = probe.get("timeoutSeconds").and_then(Value::as_i64).unwrap_or(1);
+                let period = probe.get("periodSeconds").and_then(Value::as_i64).unwrap_or(10);
+                if timeout >= period {
+                    violations.push(Violation::new(
+                        format!("{} {} timeoutSeconds ({}) is not below periodSeconds ({}).", container.label(), probe_name, timeout, period),
+                        format!("{}.{}.timeoutSeconds", container.path, probe_name),
+                    ));
+                }
+            }
+
+            let Some(liveness) = liveness else { continue };
+            if liveness.get("failureThreshold").and_then(Value::as_i64) == Some(1) {
+                violations.push(Violation::new(
+                    format!("{} livenessProbe restarts the container after a single failure.", container.label()),
+                    format!("{}.livenessProbe.failureThreshold", container.path),
+                ));
+            }
+
+            let image = container.value.get("image").and_then(Value::as_str).unwrap_or_default();
+            let initial_delay = liveness.get("initialDelaySeconds").and_then(Value::as_i64).unwrap_or(0);
+            if initial_delay == 0 && container.value.get("startupProbe").is_none() && self.is_slow_start(image) {
+                violations.push(Violation::new(
+                    format!("{} image '{}' starts slowly but livenessProbe has no initialDelaySeconds or startupProbe.", container.label(), image),
+                    format!("{}.livenessProbe.initialDelaySeconds", container.path),
+                ));
+            }
+        }
+        violations
+    }
+}
+
+/// Flags long liveness `initialDelaySeconds` without a `startupProbe`. A
+/// startup probe holds off liveness checks only as long as the app actually
+/// takes to start, instead of a fixed delay that is either too short
+/// (restart loops) or too long (slow recovery).
+pub struct StartupProbeRule {
+    max_initial_delay: i64,
+}
+
+impl StartupProbeRule {
+    pub fn new(max_initial_delay: i64) -> Self {
+        StartupProbeRule { max_initial_delay }
+    }
+}
+
+impl LintRule for StartupProbeRule {
+    fn id(&self) -> &'static str {
+        "startup-probe"
+    }
+
+    fn targets(&self, doc: &Value) -> bool {
+        workload::pod_spec(doc).is_some_and(|pod| !pod.is_batch())
+    }
+
+    fn check(&self, doc: &Value) -> Vec<Violation> {
+        let Some(pod) = workload::pod_spec(doc).filter(|pod| !pod.is_batch()) else { return vec![] };
+
+        let mut violations = vec![];
+        for container in pod.all_containers().into_iter().filter(|c| c.is_long_running()) {
+            if container.value.get("startupProbe").is_some() {
+                continue;
+            }
+            let Some(delay) = container
+                .value
+                .get("livenessProbe")
+                .and_then(|probe| probe.get("initialDelaySeconds"))
+                .and_then(Value::as_i64)
+            else {
+                continue;
+            };
+            if delay > self.max_initial_delay {
+                violations.push(Violation::new(
+                    format!(
+                        "{} delays livenessProbe by {}s; use a startupProbe for slow starts instead.",
+                        container.label(),
+                        delay
+                    ),
+                    format!("{}.startupProbe", container.path),
+                ));
             }
         }
-        return None
+        violations
     }
-}
\ No newline at end of file
+}
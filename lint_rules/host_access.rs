@@ -0,0 +1,136 @@
+use serde_yaml::Value;
+
+use super::{workload, LintRule, Violation};
+use crate::utils;
+
+/// Flags `hostPath` volumes, a common container-escape vector. Paths
+/// matching an allowlisted glob are accepted.
+pub struct HostPathRule {
+    allowed_paths: Vec<String>,
+}
+
+impl HostPathRule {
+    pub fn new(allowed_paths: Vec<String>) -> Self {
+        HostPathRule { allowed_paths }
+    }
+}
+
+impl LintRule for HostPathRule {
+    fn id(&self) -> &'static str {
+        "host-path"
+    }
+
+    fn targets(&self, doc: &Value) -> bool {
+        workload::pod_spec(doc).is_some()
+    }
+
+    fn check(&self, doc: &Value) -> Vec<Violation> {
+        let Some(pod) = workload::pod_spec(doc) else { return vec![] };
+        let Some(volumes) = pod.spec.get("volumes").and_then(Value::as_sequence) else { return vec![] };
+
+        let mut violations = vec![];
+        for (i, volume) in volumes.iter().enumerate() {
+            let Some(host_path) = volume.get("hostPath") else { continue };
+            let path = host_path.get("path").and_then(Value::as_str).unwrap_or_default();
+            if self.allowed_paths.iter().any(|pattern| utils::glob_match(pattern, path)) {
+                continue;
+            }
+            let name = volume.get("name").and_then(Value::as_str).unwrap_or("unnamed");
+            violations.push(Violation::new(
+                format!("Volume '{}' mounts hostPath '{}' from the node.", name, path),
+                format!("{}.volumes[{}].hostPath.path", pod.path, i),
+            ));
+        }
+        violations
+    }
+}
+
+/// Flags pods sharing the node's network, PID or IPC namespace, none of
+/// which the Pod Security `restricted` profile allows.
+pub struct HostNamespacesRule;
+
+impl LintRule for HostNamespacesRule {
+    fn id(&self) -> &'static str {
+        "host-namespaces"
+    }
+
+    fn targets(&self, doc: &Value) -> bool {
+        workload::pod_spec(doc).is_some()
+    }
+
+    fn check(&self, doc: &Value) -> Vec<Violation> {
+        let Some(pod) = workload::pod_spec(doc) else { return vec![] };
+
+        [("hostNetwork", "network"), ("hostPID", "PID"), ("hostIPC", "IPC")]
+            .into_iter()
+            .filter(|(field, _)| pod.spec.get(field).and_then(Value::as_bool) == Some(true))
+            .map(|(field, namespace)| {
+                Violation::new(
+                    format!("Pod shares the node's {} namespace.", namespace),
+                    format!("{}.{}", pod.path, field),
+                )
+            })
+            .collect()
+    }
+}
+
+/// Flags `hostNetwork` pods without `dnsPolicy: ClusterFirstWithHostNet`.
+/// Their default `ClusterFirst` policy falls back to the node's resolver,
+/// so in-cluster Service names do not resolve.
+pub struct HostNetworkDnsRule;
+
+impl LintRule for HostNetworkDnsRule {
+    fn id(&self) -> &'static str {
+        "host-network-dns"
+    }
+
+    fn targets(&self, doc: &Value) -> bool {
+        workload::pod_spec(doc).is_some()
+    }
+
+    fn check(&self, doc: &Value) -> Vec<Violation> {
+        let Some(pod) = workload::pod_spec(doc) else { return vec![] };
+        if pod.spec.get("hostNetwork").and_then(Value::as_bool) != Some(true) {
+            return vec![];
+        }
+        match pod.spec.get("dnsPolicy").and_then(Value::as_str).unwrap_or("ClusterFirst") {
+            // Explicit opt-outs from cluster DNS.
+            "ClusterFirstWithHostNet" | "Default" | "None" => vec![],
+            policy => vec![Violation::new(
+                format!("Pod uses hostNetwork with dnsPolicy {}, so cluster DNS names will not resolve; use ClusterFirstWithHostNet.", policy),
+                format!("{}.dnsPolicy", pod.path),
+            )],
+        }
+    }
+}
+
+/// Flags container ports bound to a `hostPort`, which limits the pod to
+/// nodes where that port is free and exposes it on the node's address.
+pub struct HostPortRule;
+
+impl LintRule for HostPortRule {
+    fn id(&self) -> &'static str {
+        "host-port"
+    }
+
+    fn targets(&self, doc: &Value) -> bool {
+        workload::pod_spec(doc).is_some()
+    }
+
+    fn check(&self, doc: &Value) -> Vec<Violation> {
+        let Some(pod) = workload::pod_spec(doc) else { return vec![] };
+
+        let mut violations = vec![];
+        for container in pod.all_containers() {
+            let ports = container.value.get("ports").and_then(Value::as_sequence).into_iter().flatten();
+            for (i, port) in ports.enumerate() {
+                let Some(host_port) = port.get("hostPort").and_then(Value::as_i64) else { continue };
+                violations.push(Violation::new(
+                    format!("{} binds hostPort {} on the node; expose it through a Service instead.", container.label(), host_port),
+                    format!("{}.ports[{}].hostPort", container.path, i),
+                ));
+            }
+        }
+        violations
+    }
+}
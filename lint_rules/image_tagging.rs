@@ -1,22 +1,203 @@
 use serde_yaml::Value;
-use super::LintRule;
+use super::{workload, LintRule, Violation};
+use crate::utils;
 
 pub struct LatestImageTagRule;
 
 impl LintRule for LatestImageTagRule {
-    fn check(&self, doc: &serde_yaml::Value) -> Option<String> {
-        let containers = doc.get("spec")?
-        .get("template")?.get("spec")?
-        .get("containers")?
-        .as_sequence()?;
-
-        for container in containers {
-            if let Some(image) = container.get("image").and_then(Value::as_str) {
+    fn id(&self) -> &'static str {
+        "latest-image-tag"
+    }
+
+    fn targets(&self, doc: &Value) -> bool {
+        workload::pod_spec(doc).is_some()
+    }
+
+    fn check(&self, doc: &serde_yaml::Value) -> Vec<Violation> {
+        let Some(pod) = workload::pod_spec(doc) else { return vec![] };
+
+        let mut violations = vec![];
+        for container in pod.all_containers() {
+            if let Some(image) = container.value.get("image").and_then(Value::as_str) {
                 if image.ends_with(":latest") {
-                    return Some("Container uses a 'latest' image tag. Which should be avoided. ".to_string());
+                    violations.push(Violation::new(
+                        format!("{} uses a 'latest' image tag. Which should be avoided. ", container.label()),
+                        format!("{}.image", container.path),
+                    ));
                 }
+            }
+        }
+        violations
+    }
+}
+
+/// Flags pull policies that contradict the image reference: `Always` on a
+/// digest-pinned image, `Never` outside development clusters, and the
+/// implicit `Always` Kubernetes applies to untagged and `:latest` images.
+pub struct ImagePullPolicyRule {
+    dev_cluster: bool,
+}
+
+impl ImagePullPolicyRule {
+    pub fn new(dev_cluster: bool) -> Self {
+        ImagePullPolicyRule { dev_cluster }
+    }
+}
+
+impl LintRule for ImagePullPolicyRule {
+    fn id(&self) -> &'static str {
+        "image-pull-policy"
+    }
+
+    fn targets(&self, doc: &Value) -> bool {
+        workload::pod_spec(doc).is_some()
+    }
+
+    fn check(&self, doc: &Value) -> Vec<Violation> {
+        let Some(pod) = workload::pod_spec(doc) else { return vec![] };
+
+        let mut violations = vec![];
+        for container in pod.all_containers() {
+            let Some(image) = container.value.get("image").and_then(Value::as_str) else { continue };
+            let policy = container.value.get("imagePullPolicy").and_then(Value::as_str);
+            let field = format!("{}.imagePullPolicy", container.path);
+
+            let message = match policy {
+                Some("Always") if image.contains('@') => {
+                    format!("{} pulls digest-pinned image '{}' with imagePullPolicy: Always.", container.label(), image)
+                }
+                Some("Never") if !self.dev_cluster => {
+                    format!("{} uses imagePullPolicy: Never, which only works with preloaded images.", container.label())
+                }
+                None if is_latest(image) => format!(
+                    "{} image '{}' is pulled on every start because it is untagged or ':latest'; set imagePullPolicy explicitly.",
+                    container.label(),
+                    image
+                ),
+                _ => continue,
+            };
+            violations.push(Violation::new(message, field));
+        }
+        violations
+    }
+}
+
+/// Untagged or `:latest`, which Kubernetes treats as `imagePullPolicy: Always`.
+fn is_latest(image: &str) -> bool {
+    if image.contains('@') {
+        return false;
+    }
+    // A `:` before the last `/` belongs to a registry port, not a tag.
+    let name = image.rsplit('/').next().unwrap_or(image);
+    match name.split_once(':') {
+        Some((_, tag)) => tag == "latest",
+        None => true,
+    }
+}
+
+/// Flags images from registries outside the configured allowlist. Patterns
+/// are globs over the image repository without tag or digest, with Docker
+/// Hub images expanded to `docker.io/library/nginx` form; a trailing `/*`
+/// covers every repository below that path. An empty allowlist allows all.
+pub struct AllowedRegistriesRule {
+    allowed: Vec<String>,
+}
+
+impl AllowedRegistriesRule {
+    pub fn new(allowed: Vec<String>) -> Self {
+        AllowedRegistriesRule { allowed }
+    }
+
+    fn is_allowed(&self, repository: &str) -> bool {
+        self.allowed.iter().any(|pattern| {
+            utils::glob_match(pattern, repository)
+                || pattern.strip_suffix("/*").is_some_and(|prefix| utils::glob_match(&format!("{}/**", prefix), repository))
+        })
+    }
+}
+
+impl LintRule for AllowedRegistriesRule {
+    fn id(&self) -> &'static str {
+        "allowed-registries"
+    }
+
+    fn targets(&self, doc: &Value) -> bool {
+        !self.allowed.is_empty() && workload::pod_spec(doc).is_some()
+    }
+
+    fn check(&self, doc: &Value) -> Vec<Violation> {
+        if self.allowed.is_empty() {
+            return vec![];
+        }
+        let Some(pod) = workload::pod_spec(doc) else { return vec![] };
+
+        let mut violations = vec![];
+        for container in pod.all_containers() {
+            let Some(image) = container.value.get("image").and_then(Value::as_str) else { continue };
+            if !self.is_allowed(&repository(image)) {
+                violations.push(Violation::new(
+                    format!("{} image '{}' is not from an allowed registry.", container.label(), image),
+                    format!("{}.image", container.path),
+                ));
+            }
+        }
+        violations
+    }
+}
+
+/// The fully qualified repository of `image`, without tag or digest.
+fn repository(image: &str) -> String {
+    let image = image.split('@').next().unwrap_or(image);
+    let image = match image.rsplit_once(':') {
+        Some((repository, tag)) if !tag.contains('/') => repository,
+        _ => image,
+    };
+
+    // The first segment is a registry host only if it looks like one.
+    match image.split_once('/') {
+        Some((host, _)) if host.contains(['.', ':']) || host == "localhost" => image.to_string(),
+        Some(_) => format!("docker.io/{}", image),
+        None => format!("docker.io/library/{}", image),
+    }
+}
+
+/// Opt-in for production clusters: every image must be pinned by digest
+/// (`image@sha256:...`), since tags can be moved to different content.
+pub struct ImageDigestRule {
+    enabled: bool,
+}
+
+impl ImageDigestRule {
+    pub fn new(enabled: bool) -> Self {
+        ImageDigestRule { enabled }
+    }
+}
+
+impl LintRule for ImageDigestRule {
+    fn id(&self) -> &'static str {
+        "image-digest"
+    }
+
+    fn targets(&self, doc: &Value) -> bool {
+        self.enabled && workload::pod_spec(doc).is_some()
+    }
+
+    fn check(&self, doc: &Value) -> Vec<Violation> {
+        if !self.enabled {
+            return vec![];
+        }
+        let Some(pod) = workload::pod_spec(doc) else { return vec![] };
+
+        let mut violations = vec![];
+        for container in pod.all_containers() {
+            let Some(image) = container.value.get("image").and_then(Value::as_str) else { continue };
+            if !image.contains("@sha256:") {
+                violations.push(Violation::new(
+                    format!("{} image '{}' is not pinned by digest.", container.label(), image),
+                    format!("{}.image", container.path),
+                ));
+            }
         }
+        violations
     }
-    return None
 }
-}
\ No newline at end of file
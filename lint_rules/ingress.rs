@@ -0,0 +1,102 @@
+use serde_yaml::Value;
+
+use super::{is_kind, LintRule, Violation};
+use crate::utils;
+
+/// Flags Ingress hosts not covered by a `spec.tls` entry. Hosts matching a
+/// configured plaintext glob (e.g. `*.internal.example.com`) are allowed.
+pub struct IngressTlsRule {
+    plaintext_hosts: Vec<String>,
+}
+
+impl IngressTlsRule {
+    pub fn new(plaintext_hosts: Vec<String>) -> Self {
+        IngressTlsRule { plaintext_hosts }
+    }
+}
+
+impl LintRule for IngressTlsRule {
+    fn id(&self) -> &'static str {
+        "ingress-tls"
+    }
+
+    fn targets(&self, doc: &Value) -> bool {
+        is_kind(doc, &["Ingress"])
+    }
+
+    fn check(&self, doc: &Value) -> Vec<Violation> {
+        if doc.get("kind").and_then(Value::as_str) != Some("Ingress") {
+            return vec![];
+        }
+        let spec = doc.get("spec");
+        let tls_hosts: Vec<&str> = spec
+            .and_then(|s| s.get("tls"))
+            .and_then(Value::as_sequence)
+            .into_iter()
+            .flatten()
+            .flat_map(|tls| tls.get("hosts").and_then(Value::as_sequence).into_iter().flatten())
+            .filter_map(Value::as_str)
+            .collect();
+
+        let mut violations = vec![];
+        let rules = spec.and_then(|s| s.get("rules")).and_then(Value::as_sequence).into_iter().flatten();
+        for (i, rule) in rules.enumerate() {
+            let Some(host) = rule.get("host").and_then(Value::as_str) else { continue };
+            if self.plaintext_hosts.iter().any(|pattern| utils::glob_match(pattern, host)) {
+                continue;
+            }
+            if !tls_hosts.iter().any(|tls_host| covers(tls_host, host)) {
+                violations.push(Violation::new(
+                    format!("Ingress host '{}' is not covered by a spec.tls entry.", host),
+                    format!("spec.rules[{}].host", i),
+                ));
+            }
+        }
+        violations
+    }
+}
+
+/// Flags Ingresses without `spec.ingressClassName`, whether they rely on
+/// the deprecated `kubernetes.io/ingress.class` annotation or on whatever
+/// the cluster default class happens to be.
+pub struct IngressClassRule;
+
+impl LintRule for IngressClassRule {
+    fn id(&self) -> &'static str {
+        "ingress-class"
+    }
+
+    fn targets(&self, doc: &Value) -> bool {
+        is_kind(doc, &["Ingress"])
+    }
+
+    fn check(&self, doc: &Value) -> Vec<Violation> {
+        if doc.get("kind").and_then(Value::as_str) != Some("Ingress") {
+            return vec![];
+        }
+        if doc.get("spec").and_then(|s| s.get("ingressClassName")).and_then(Value::as_str).is_some() {
+            return vec![];
+        }
+        let annotation = doc
+            .get("metadata")
+            .and_then(|m| m.get("annotations"))
+            .and_then(|a| a.get("kubernetes.io/ingress.class"))
+            .and_then(Value::as_str);
+        let message = match annotation {
+            Some(class) => format!(
+                "Ingress uses the deprecated kubernetes.io/ingress.class annotation; set spec.ingressClassName: {} instead.",
+                class
+            ),
+            None => "Ingress has no spec.ingressClassName and depends on the cluster default class.".to_string(),
+        };
+        vec![Violation::new(message, "spec.ingressClassName")]
+    }
+}
+
+/// Whether a TLS host, possibly a `*.` wildcard for one label, covers `host`.
+fn covers(tls_host: &str, host: &str) -> bool {
+    match tls_host.strip_prefix("*.") {
+        Some(domain) => host.split_once('.').is_some_and(|(_, rest)| rest == domain),
+        None => tls_host == host,
+    }
+}
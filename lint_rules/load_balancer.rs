@@ -0,0 +1,277 @@
+use serde_yaml::Value;
+
+use super::{is_kind, LintRule, Violation};
+use crate::config::{ExternalTrafficConfig, LoadBalancersConfig};
+use crate::utils;
+
+/// Annotations that make a cloud load balancer internal, with the values
+/// doing so. Cloud controllers compare values case-sensitively, and so do
+/// we; GKE still accepts the deprecated lowercase `internal`.
+const INTERNAL_ANNOTATIONS: &[(&str, &[&str])] = &[
+    ("service.beta.kubernetes.io/aws-load-balancer-internal", &["true"]),
+    ("service.beta.kubernetes.io/aws-load-balancer-scheme", &["internal"]),
+    ("networking.gke.io/load-balancer-type", &["Internal", "internal"]),
+    ("cloud.google.com/load-balancer-type", &["Internal", "internal"]),
+    ("service.beta.kubernetes.io/azure-load-balancer-internal", &["true"]),
+    ("service.beta.kubernetes.io/oci-load-balancer-internal", &["true"]),
+];
+
+/// Annotations with a fixed set of valid values, compared case-sensitively
+/// like `INTERNAL_ANNOTATIONS`; anything else is silently ignored by the
+/// cloud controller.
+const ENUM_ANNOTATIONS: &[(&str, &[&str])] = &[
+    ("service.beta.kubernetes.io/aws-load-balancer-scheme", &["internal", "internet-facing"]),
+    ("networking.gke.io/load-balancer-type", &["Internal", "External", "internal"]),
+    ("cloud.google.com/load-balancer-type", &["Internal", "External", "internal"]),
+    ("service.beta.kubernetes.io/aws-load-balancer-internal", &["true", "false"]),
+    ("service.beta.kubernetes.io/azure-load-balancer-internal", &["true", "false"]),
+];
+
+const SOURCE_RANGES_ANNOTATION: &str = "service.beta.kubernetes.io/load-balancer-source-ranges";
+
+/// Validates LoadBalancer Services: cloud annotations must have valid
+/// values, and internet-facing ones must restrict their source ranges. In
+/// restricted environments every load balancer must set source ranges,
+/// drawn from the configured CIDRs when there are any.
+pub struct LoadBalancerRule {
+    config: LoadBalancersConfig,
+}
+
+impl LoadBalancerRule {
+    pub fn new(config: LoadBalancersConfig) -> Self {
+        LoadBalancerRule { config }
+    }
+
+    /// Remediation hint naming the configured CIDRs, if any.
+    fn suggestion(&self) -> String {
+        if self.config.source_ranges.is_empty() {
+            return String::new();
+        }
+        format!(" Set loadBalancerSourceRanges: [{}].", self.config.source_ranges.join(", "))
+    }
+}
+
+impl LintRule for LoadBalancerRule {
+    fn id(&self) -> &'static str {
+        "load-balancer"
+    }
+
+    fn targets(&self, doc: &Value) -> bool {
+        is_kind(doc, &["Service"])
+    }
+
+    fn check(&self, doc: &Value) -> Vec<Violation> {
+        if !is_load_balancer(doc) {
+            return vec![];
+        }
+        let mut violations = vec![];
+
+        for (annotation, allowed) in ENUM_ANNOTATIONS {
+            let Some(value) = annotation_value(doc, annotation) else { continue };
+            if !allowed.contains(&value) {
+                violations.push(Violation::new(
+                    format!("Annotation {} is '{}'; use one of {}.", annotation, value, allowed.join(", ")),
+                    "metadata.annotations",
+                ));
+            }
+        }
+
+        let internal = is_internal(doc);
+        if internal && !self.config.restricted {
+            return violations;
+        }
+        let mut ranges: Vec<&str> = doc
+            .get("spec")
+            .and_then(|s| s.get("loadBalancerSourceRanges"))
+            .and_then(Value::as_sequence)
+            .into_iter()
+            .flatten()
+            .filter_map(Value::as_str)
+            .collect();
+        ranges.extend(annotation_value(doc, SOURCE_RANGES_ANNOTATION).into_iter().flat_map(|v| v.split(',')).map(str::trim));
+
+        let description = if internal { "Internal LoadBalancer" } else { "Internet-facing LoadBalancer" };
+        if ranges.is_empty() {
+            violations.push(Violation::new(
+                format!("{} does not restrict loadBalancerSourceRanges.{}", description, self.suggestion()),
+                "spec.loadBalancerSourceRanges",
+            ));
+        } else if ranges.iter().any(|range| matches!(*range, "0.0.0.0/0" | "::/0")) {
+            violations.push(Violation::new(
+                format!("{} allows traffic from anywhere.{}", description, self.suggestion()),
+                "spec.loadBalancerSourceRanges",
+            ));
+        } else if self.config.restricted && !self.config.source_ranges.is_empty() {
+            for range in ranges.iter().filter(|range| !self.config.source_ranges.iter().any(|allowed| allowed == *range)) {
+                violations.push(Violation::new(
+                    format!("Source range {} is not one of the allowed CIDRs.{}", range, self.suggestion()),
+                    "spec.loadBalancerSourceRanges",
+                ));
+            }
+        }
+        violations
+    }
+}
+
+/// Requires LoadBalancer Services under the configured internal paths
+/// (e.g. `**/internal/**`) to carry an internal load balancer annotation.
+pub struct InternalLoadBalancerRule {
+    internal_paths: Vec<String>,
+}
+
+impl InternalLoadBalancerRule {
+    pub fn new(internal_paths: Vec<String>) -> Self {
+        InternalLoadBalancerRule { internal_paths }
+    }
+}
+
+impl LintRule for InternalLoadBalancerRule {
+    fn id(&self) -> &'static str {
+        "internal-load-balancer"
+    }
+
+    fn targets(&self, doc: &Value) -> bool {
+        is_kind(doc, &["Service"])
+    }
+
+    fn applies_to(&self, path: &str) -> bool {
+        self.internal_paths.iter().any(|pattern| utils::glob_match(pattern, path))
+    }
+
+    fn check(&self, doc: &Value) -> Vec<Violation> {
+        if !is_load_balancer(doc) || is_internal(doc) {
+            return vec![];
+        }
+        vec![Violation::new(
+            "LoadBalancer must be internal here but has no internal load balancer annotation.",
+            "metadata.annotations",
+        )]
+    }
+}
+
+/// Flags NodePort Services and hardcoded `nodePort` values, which open a
+/// port on every node and collide across Services; in the cloud,
+/// LoadBalancer Services and Ingresses are the sanctioned way in. Off for
+/// on-prem clusters.
+pub struct NodePortRule {
+    on_prem: bool,
+}
+
+impl NodePortRule {
+    pub fn new(on_prem: bool) -> Self {
+        NodePortRule { on_prem }
+    }
+}
+
+impl LintRule for NodePortRule {
+    fn id(&self) -> &'static str {
+        "node-port"
+    }
+
+    fn targets(&self, doc: &Value) -> bool {
+        !self.on_prem && is_kind(doc, &["Service"])
+    }
+
+    fn check(&self, doc: &Value) -> Vec<Violation> {
+        if self.on_prem || doc.get("kind").and_then(Value::as_str) != Some("Service") {
+            return vec![];
+        }
+        let spec = doc.get("spec");
+
+        let mut violations = vec![];
+        if spec.and_then(|s| s.get("type")).and_then(Value::as_str) == Some("NodePort") {
+            violations.push(Violation::warning(
+                "NodePort Service exposes a port on every node; use a LoadBalancer Service or an Ingress.",
+                "spec.type",
+            ));
+        }
+        let ports = spec.and_then(|s| s.get("ports")).and_then(Value::as_sequence).into_iter().flatten();
+        for (i, port) in ports.enumerate() {
+            if let Some(node_port) = port.get("nodePort").and_then(Value::as_i64) {
+                violations.push(Violation::warning(
+                    format!("nodePort {} is hardcoded and may collide with other Services; let Kubernetes allocate it.", node_port),
+                    format!("spec.ports[{}].nodePort", i),
+                ));
+            }
+        }
+        violations
+    }
+}
+
+/// Checks `externalTrafficPolicy` on LoadBalancer and NodePort Services.
+/// Where clients reach NodePorts directly, by default on on-prem clusters,
+/// `Local` NodePorts drop clients reaching a node without a ready pod.
+/// Depending on config, `Cluster` is flagged for hiding client source IPs,
+/// and `Local` LoadBalancers must pin their `healthCheckNodePort`.
+pub struct ExternalTrafficPolicyRule {
+    config: ExternalTrafficConfig,
+    warn_local_node_ports: bool,
+}
+
+impl ExternalTrafficPolicyRule {
+    pub fn new(config: ExternalTrafficConfig, on_prem: bool) -> Self {
+        let warn_local_node_ports = config.warn_local_node_ports.unwrap_or(on_prem);
+        ExternalTrafficPolicyRule { config, warn_local_node_ports }
+    }
+}
+
+impl LintRule for ExternalTrafficPolicyRule {
+    fn id(&self) -> &'static str {
+        "external-traffic-policy"
+    }
+
+    fn targets(&self, doc: &Value) -> bool {
+        let ExternalTrafficConfig { preserve_source_ip, pin_health_check_port, .. } = self.config;
+        (preserve_source_ip || pin_health_check_port || self.warn_local_node_ports) && is_kind(doc, &["Service"])
+    }
+
+    fn check(&self, doc: &Value) -> Vec<Violation> {
+        let spec = doc.get("spec");
+        let service_type = spec.and_then(|s| s.get("type")).and_then(Value::as_str);
+        if doc.get("kind").and_then(Value::as_str) != Some("Service") || !matches!(service_type, Some("LoadBalancer" | "NodePort")) {
+            return vec![];
+        }
+        let policy = spec.and_then(|s| s.get("externalTrafficPolicy")).and_then(Value::as_str).unwrap_or("Cluster");
+        let service_type = service_type.unwrap_or_default();
+
+        let mut violations = vec![];
+        if policy != "Local" {
+            if self.config.preserve_source_ip {
+                violations.push(Violation::new(
+                    format!("{} Service hides client source IPs; set externalTrafficPolicy: Local.", service_type),
+                    "spec.externalTrafficPolicy",
+                ));
+            }
+            return violations;
+        }
+        if service_type == "NodePort" {
+            if self.warn_local_node_ports {
+                violations.push(Violation::warning(
+                    "NodePort Service with externalTrafficPolicy: Local drops clients that reach a node without a ready pod.",
+                    "spec.externalTrafficPolicy",
+                ));
+            }
+        } else if self.config.pin_health_check_port && spec.and_then(|s| s.get("healthCheckNodePort")).is_none() {
+            violations.push(Violation::new(
+                "LoadBalancer with externalTrafficPolicy: Local must pin healthCheckNodePort so load balancer health checks can reach it.",
+                "spec.healthCheckNodePort",
+            ));
+        }
+        violations
+    }
+}
+
+fn is_load_balancer(doc: &Value) -> bool {
+    doc.get("kind").and_then(Value::as_str) == Some("Service")
+        && doc.get("spec").and_then(|s| s.get("type")).and_then(Value::as_str) == Some("LoadBalancer")
+}
+
+fn is_internal(doc: &Value) -> bool {
+    INTERNAL_ANNOTATIONS
+        .iter()
+        .any(|(annotation, internal)| annotation_value(doc, annotation).is_some_and(|v| internal.contains(&v)))
+}
+
+fn annotation_value<'a>(doc: &'a Value, annotation: &str) -> Option<&'a str> {
+    doc.get("metadata")?.get("annotations")?.get(annotation)?.as_str()
+}
@@ -1,16 +1,54 @@
 use serde_yaml::Value;
 
-use super::LintRule;
+use super::{LintRule, Violation};
 
 pub struct MissingLabelsRule;
 
 impl LintRule for MissingLabelsRule {
-    fn check(&self, doc: &Value) -> Option<String> {
+    fn id(&self) -> &'static str {
+        "missing-labels"
+    }
+
+    fn check(&self, doc: &Value) -> Vec<Violation> {
         if let Some(metadata) = doc.get("metadata") {
             if metadata.get("labels").is_none() {
-                return Some("Resource is missing labels.".to_string());
+                return vec![Violation::new("Resource is missing labels.", "metadata.labels")];
             }
         }
-        None
+        vec![]
+    }
+}
+
+/// Requires the configured label set, by default the recommended
+/// `app.kubernetes.io/*` labels.
+pub struct RecommendedLabelsRule {
+    required: Vec<String>,
+}
+
+impl RecommendedLabelsRule {
+    pub fn new(required: Vec<String>) -> Self {
+        RecommendedLabelsRule { required }
+    }
+}
+
+impl LintRule for RecommendedLabelsRule {
+    fn id(&self) -> &'static str {
+        "recommended-labels"
+    }
+
+    fn check(&self, doc: &Value) -> Vec<Violation> {
+        let Some(metadata) = doc.get("metadata") else { return vec![] };
+        let labels = metadata.get("labels");
+
+        let missing: Vec<&str> = self
+            .required
+            .iter()
+            .filter(|label| labels.and_then(|l| l.get(label.as_str())).is_none())
+            .map(String::as_str)
+            .collect();
+        if missing.is_empty() {
+            return vec![];
+        }
+        vec![Violation::new(format!("Resource is missing recommended labels: {}.", missing.join(", ")), "metadata.labels")]
     }
 }
@@ -3,13 +3,317 @@ pub mod resource_limits;
 pub mod security; 
 pub mod health_checks;
 pub mod image_tagging;
+pub mod sample_manifests;
+pub mod node_os;
+pub mod rollout_strategy;
+pub mod workload;
+pub mod host_access;
+pub mod storage;
+pub mod service_account;
+pub mod downward_api;
+pub mod namespace;
+pub mod config_reload;
+pub mod progressive_delivery;
+pub mod availability;
+pub mod autoscaling;
+pub mod traffic;
+pub mod shutdown;
+pub mod load_balancer;
+pub mod api_versions;
+pub mod ingress;
+pub mod slo;
+pub mod references;
+pub mod placeholders;
+pub mod secrets;
+pub mod batch;
+pub mod stateful_set;
+pub mod priority;
+pub mod selectors;
+pub mod network_policy;
+pub mod rbac;
+pub mod env;
+pub mod duplicates;
 
-pub use missing_labels::MissingLabelsRule;
-pub use resource_limits::ResourceLimitsRule;
-pub use security::{RunAsNonRootRule, ReadOnlyRootFilesystemRule};
-pub use health_checks::{LivenessProbeRule, ReadinessProbeRule};
-pub use image_tagging::LatestImageTagRule;
+pub use missing_labels::{MissingLabelsRule, RecommendedLabelsRule};
+pub use resource_limits::{ResourceLimitsRule, QosRule, CpuLimitsRule};
+pub use security::{RunAsNonRootRule, ReadOnlyRootFilesystemRule, WritablePathsRule, AllowPrivilegeEscalationRule, SeccompProfileRule, CapabilitiesRule};
+pub use health_checks::{LivenessProbeRule, ReadinessProbeRule, ProbeSanityRule, StartupProbeRule};
+pub use image_tagging::{LatestImageTagRule, ImagePullPolicyRule, AllowedRegistriesRule, ImageDigestRule};
+pub use sample_manifests::SampleManifestRule;
+pub use node_os::NodeOsSelectorRule;
+pub use rollout_strategy::ZeroDowntimeStrategyRule;
+pub use host_access::{HostPathRule, HostNamespacesRule, HostNetworkDnsRule, HostPortRule};
+pub use storage::{StorageRetentionRule, EmptyDirSizeLimitRule};
+pub use service_account::AutomountServiceAccountTokenRule;
+pub use downward_api::DownwardApiRule;
+pub use namespace::{DefaultNamespaceRule, ClusterScopedNamespaceRule, ClusterDirectoryRule, NamespaceExistsRule};
+pub use config_reload::ConfigReloadRule;
+pub use progressive_delivery::{ArgoRolloutRule, FlaggerCanaryRule};
+pub use availability::{SingleReplicaRule, PodDisruptionBudgetRule, ReplicaSpreadRule};
+pub use autoscaling::{HpaMetricsRule, HpaTargetRule, KedaScaledObjectRule};
+pub use traffic::{TopologyRoutingRule, ServiceTargetPortRule, ContainerPortsRule};
+pub use shutdown::GracefulShutdownRule;
+pub use load_balancer::{LoadBalancerRule, InternalLoadBalancerRule, NodePortRule, ExternalTrafficPolicyRule};
+pub use api_versions::DeprecatedApiRule;
+pub use ingress::{IngressClassRule, IngressTlsRule};
+pub use slo::SloAnnotationsRule;
+pub use references::{ConfigReferencesRule, ImagePullSecretsRule};
+pub use placeholders::UnrenderedPlaceholderRule;
+pub use secrets::{SecretCredentialsRule, EnvSecretsRule};
+pub use batch::{CronJobSettingsRule, CronJobScheduleRule, JobSettingsRule};
+pub use stateful_set::StatefulSetRule;
+pub use priority::PriorityClassRule;
+pub use network_policy::NetworkPolicyRule;
+pub use rbac::{RbacWildcardRule, RbacClusterSecretsRule, RbacBindingsRule};
+pub use env::DuplicateEnvRule;
+pub use duplicates::DuplicateResourceRule;
+
+use std::collections::HashMap;
+
+use crate::config::Config;
+
+/// A problem found by a rule. `field` is the dotted path of the offending
+/// (or missing) field, e.g. `spec.template.spec.containers[0].image`.
+#[derive(Debug, Clone)]
+pub struct Violation {
+    pub message: String,
+    pub field: String,
+    pub severity: Severity,
+}
+
+impl Violation {
+    pub fn new(message: impl Into<String>, field: impl Into<String>) -> Self {
+        Violation { message: message.into(), field: field.into(), severity: Severity::Error }
+    }
+
+    /// A violation the linter cannot confirm from the input alone.
+    pub fn warning(message: impl Into<String>, field: impl Into<String>) -> Self {
+        Violation { severity: Severity::Warning, ..Violation::new(message, field) }
+    }
+
+    /// A violation that must block the change, such as a leaked credential.
+    pub fn critical(message: impl Into<String>, field: impl Into<String>) -> Self {
+        Violation { severity: Severity::Critical, ..Violation::new(message, field) }
+    }
+}
+
+/// Ordered from least to most severe.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Severity {
+    Warning,
+    #[default]
+    Error,
+    Critical,
+}
+
+impl Severity {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Severity::Warning => "warning",
+            Severity::Error => "error",
+            Severity::Critical => "critical",
+        }
+    }
+
+    /// Parses the names `as_str` returns.
+    pub fn parse(name: &str) -> Option<Severity> {
+        [Severity::Warning, Severity::Error, Severity::Critical].into_iter().find(|severity| severity.as_str() == name)
+    }
+}
 
 pub trait LintRule {
-    fn check(&self, doc: &serde_yaml::Value) -> Option<String>;
+    /// Stable identifier used by `--rules` and the config file.
+    fn id(&self) -> &'static str;
+    /// Whether the rule runs for resources in the file at `path`.
+    fn applies_to(&self, _path: &str) -> bool {
+        true
+    }
+    /// Whether `doc` is a resource this rule checks, e.g. any workload for
+    /// container rules. Lets callers tell a rule that found nothing apart
+    /// from one that had nothing to look at, so rules that config or the
+    /// cluster profile turn off target nothing.
+    fn targets(&self, _doc: &serde_yaml::Value) -> bool {
+        true
+    }
+    fn check(&self, doc: &serde_yaml::Value) -> Vec<Violation>;
+    /// Like `check`, with every resource in the input set available. Only
+    /// rules that correlate resources need to override this.
+    fn check_in_set(&self, doc: &serde_yaml::Value, _set: &ResourceSet<'_>) -> Vec<Violation> {
+        self.check(doc)
+    }
+}
+
+/// Every document in the input set, for rules that look at resources other
+/// than the one being checked.
+#[derive(Debug, Default)]
+pub struct ResourceSet<'a> {
+    docs: Vec<&'a serde_yaml::Value>,
+    /// Where each of `docs` came from, when known.
+    origins: Vec<Origin<'a>>,
+    /// Positions in `docs` by kind, namespace and name.
+    identities: HashMap<Identity<'a>, Vec<usize>>,
+}
+
+type Identity<'a> = (&'a str, Option<&'a str>, &'a str);
+
+/// The file a document in a `ResourceSet` was read from, and its index
+/// among that file's documents.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Origin<'a> {
+    pub path: &'a str,
+    pub index: usize,
+}
+
+impl<'a> ResourceSet<'a> {
+    pub fn new(docs: impl IntoIterator<Item = &'a serde_yaml::Value>) -> Self {
+        Self::indexed(docs.into_iter().collect(), vec![])
+    }
+
+    /// Like `new`, remembering where each document came from.
+    pub fn with_origins(docs: impl IntoIterator<Item = (Origin<'a>, &'a serde_yaml::Value)>) -> Self {
+        let (origins, docs) = docs.into_iter().unzip();
+        Self::indexed(docs, origins)
+    }
+
+    fn indexed(docs: Vec<&'a serde_yaml::Value>, origins: Vec<Origin<'a>>) -> Self {
+        let mut identities: HashMap<Identity<'a>, Vec<usize>> = HashMap::new();
+        for (position, doc) in docs.iter().enumerate() {
+            let kind = doc.get("kind").and_then(serde_yaml::Value::as_str);
+            if let (Some(kind), Some(name)) = (kind, metadata_name(doc)) {
+                identities.entry((kind, metadata_namespace(doc), name)).or_default().push(position);
+            }
+        }
+        ResourceSet { docs, origins, identities }
+    }
+
+    /// Documents of `kind` named `name` in exactly `namespace`, where an
+    /// unset namespace only matches unset ones.
+    pub fn named(&self, kind: &str, name: &str, namespace: Option<&str>) -> Vec<&'a serde_yaml::Value> {
+        // The map is covariant in its keys, so it can be probed with
+        // shorter-lived strings.
+        let identities: &HashMap<Identity<'_>, Vec<usize>> = &self.identities;
+        identities.get(&(kind, namespace, name)).into_iter().flatten().map(|&position| self.docs[position]).collect()
+    }
+
+    /// Where `doc`, which must be one of the set's own documents rather
+    /// than an equal copy, came from.
+    pub fn origin(&self, doc: &serde_yaml::Value) -> Option<Origin<'a>> {
+        let index = self.docs.iter().position(|d| std::ptr::eq(*d, doc))?;
+        self.origins.get(index).copied()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.docs.is_empty()
+    }
+
+    /// Documents whose `kind` is one of `kinds`.
+    pub fn of_kind<'s>(&'s self, kinds: &'s [&str]) -> impl Iterator<Item = &'a serde_yaml::Value> + 's {
+        self.docs.iter().copied().filter(|doc| {
+            doc.get("kind").and_then(serde_yaml::Value::as_str).is_some_and(|kind| kinds.contains(&kind))
+        })
+    }
+
+    /// The resource of one of `kinds` named `name` in `namespace`.
+    pub fn find(&self, kinds: &[&str], name: &str, namespace: Option<&str>) -> Option<&'a serde_yaml::Value> {
+        self.of_kind(kinds)
+            .find(|doc| metadata_name(doc) == Some(name) && same_namespace(metadata_namespace(doc), namespace))
+    }
+}
+
+/// Whether `doc`'s kind is one of `kinds`.
+pub fn is_kind(doc: &serde_yaml::Value, kinds: &[&str]) -> bool {
+    doc.get("kind").and_then(serde_yaml::Value::as_str).is_some_and(|kind| kinds.contains(&kind))
+}
+
+pub fn metadata_name(doc: &serde_yaml::Value) -> Option<&str> {
+    doc.get("metadata").and_then(|m| m.get("name")).and_then(serde_yaml::Value::as_str)
+}
+
+pub fn metadata_namespace(doc: &serde_yaml::Value) -> Option<&str> {
+    doc.get("metadata").and_then(|m| m.get("namespace")).and_then(serde_yaml::Value::as_str)
+}
+
+/// Manifests often leave the namespace to `kubectl -n` or kustomize, so an
+/// unset namespace matches any.
+pub fn same_namespace(a: Option<&str>, b: Option<&str>) -> bool {
+    match (a, b) {
+        (Some(a), Some(b)) => a == b,
+        _ => true,
+    }
+}
+
+pub fn default_rules(config: &Config) -> Vec<Box<dyn LintRule>> {
+    vec![
+        Box::new(DuplicateResourceRule),
+        Box::new(DeprecatedApiRule::new(config.cluster_profile.version())),
+        Box::new(MissingLabelsRule),
+        Box::new(RecommendedLabelsRule::new(config.recommended_labels.required.clone())),
+        Box::new(ResourceLimitsRule),
+        Box::new(QosRule::new(config.qos.require_guaranteed)),
+        Box::new(CpuLimitsRule::new(config.cpu_limits)),
+        Box::new(LivenessProbeRule),
+        Box::new(ReadinessProbeRule),
+        Box::new(ProbeSanityRule::new(config.probes.slow_start_images.clone())),
+        Box::new(StartupProbeRule::new(config.probes.max_initial_delay)),
+        Box::new(SecretCredentialsRule),
+        Box::new(EnvSecretsRule),
+        Box::new(DuplicateEnvRule),
+        Box::new(RbacWildcardRule),
+        Box::new(RbacClusterSecretsRule),
+        Box::new(RbacBindingsRule::new(config.rbac.allowed_bindings.clone())),
+        Box::new(RunAsNonRootRule),
+        Box::new(ReadOnlyRootFilesystemRule),
+        Box::new(WritablePathsRule::new(config.read_only_root.writable_paths.clone())),
+        Box::new(AllowPrivilegeEscalationRule),
+        Box::new(SeccompProfileRule),
+        Box::new(CapabilitiesRule::new(config.capabilities.dangerous.clone())),
+        Box::new(LatestImageTagRule),
+        Box::new(ImagePullPolicyRule::new(config.cluster_profile.dev)),
+        Box::new(AllowedRegistriesRule::new(config.images.allowed_registries.clone())),
+        Box::new(ImageDigestRule::new(config.images.require_digests && config.cluster_profile.production)),
+        Box::new(UnrenderedPlaceholderRule),
+        Box::new(SampleManifestRule::new(config.sample_manifests.clone())),
+        Box::new(NodeOsSelectorRule::new(config.cluster_profile.windows_nodes)),
+        Box::new(PriorityClassRule::new(config.priority_class.clone())),
+        Box::new(ZeroDowntimeStrategyRule::new(config.zero_downtime.selector.clone())),
+        Box::new(HostPathRule::new(config.host_path.allowed_paths.clone())),
+        Box::new(HostNamespacesRule),
+        Box::new(HostNetworkDnsRule),
+        Box::new(HostPortRule),
+        Box::new(EmptyDirSizeLimitRule),
+        Box::new(StatefulSetRule::new(config.config_references.missing_as_warning)),
+        Box::new(StorageRetentionRule::new(config.storage.precious_storage_classes.clone(), config.cluster_profile.version())),
+        Box::new(AutomountServiceAccountTokenRule),
+        Box::new(DownwardApiRule),
+        Box::new(DefaultNamespaceRule::new(config.namespace.allow_unset, config.namespace.cluster_scoped_kinds.clone())),
+        Box::new(ClusterScopedNamespaceRule::new(config.namespace.cluster_scoped_kinds.clone())),
+        Box::new(ClusterDirectoryRule::new(config.namespace.cluster_paths.clone(), config.namespace.cluster_scoped_kinds.clone())),
+        Box::new(NamespaceExistsRule::new(config.namespace.existing.clone(), config.namespace.cluster_scoped_kinds.clone())),
+        Box::new(ConfigReloadRule::new(config.config_reload.reloader_annotations.clone())),
+        Box::new(ConfigReferencesRule::new(config.config_references.missing_as_warning)),
+        Box::new(ImagePullSecretsRule::new(config.config_references.missing_as_warning)),
+        Box::new(ArgoRolloutRule),
+        Box::new(FlaggerCanaryRule),
+        Box::new(SingleReplicaRule::new(config.cluster_profile.production)),
+        Box::new(PodDisruptionBudgetRule),
+        Box::new(NetworkPolicyRule::new(config.network_policy.clone())),
+        Box::new(ReplicaSpreadRule),
+        Box::new(HpaMetricsRule),
+        Box::new(HpaTargetRule),
+        Box::new(KedaScaledObjectRule),
+        Box::new(GracefulShutdownRule),
+        Box::new(CronJobSettingsRule),
+        Box::new(CronJobScheduleRule),
+        Box::new(JobSettingsRule),
+        Box::new(TopologyRoutingRule::new(config.cluster_profile.multi_zone, config.cluster_profile.version())),
+        Box::new(ServiceTargetPortRule),
+        Box::new(ContainerPortsRule),
+        Box::new(LoadBalancerRule::new(config.load_balancers.clone())),
+        Box::new(InternalLoadBalancerRule::new(config.load_balancers.internal_paths.clone())),
+        Box::new(NodePortRule::new(config.cluster_profile.on_prem)),
+        Box::new(ExternalTrafficPolicyRule::new(config.external_traffic.clone(), config.cluster_profile.on_prem)),
+        Box::new(IngressTlsRule::new(config.ingress.plaintext_hosts.clone())),
+        Box::new(IngressClassRule),
+        Box::new(SloAnnotationsRule::new(config.slo.annotations.clone(), config.cluster_profile.production)),
+    ]
 }
@@ -0,0 +1,185 @@
+use serde_yaml::Value;
+
+use super::{metadata_namespace, LintRule, ResourceSet, Violation};
+use crate::utils;
+
+/// Built-in kinds that are not namespaced. CRDs are assumed to be
+/// namespaced, like most custom resources.
+const CLUSTER_SCOPED_KINDS: &[&str] = &[
+    "Namespace",
+    "Node",
+    "PersistentVolume",
+    "StorageClass",
+    "CSIDriver",
+    "CSINode",
+    "VolumeAttachment",
+    "ClusterRole",
+    "ClusterRoleBinding",
+    "CustomResourceDefinition",
+    "APIService",
+    "MutatingWebhookConfiguration",
+    "ValidatingWebhookConfiguration",
+    "ValidatingAdmissionPolicy",
+    "ValidatingAdmissionPolicyBinding",
+    "PriorityClass",
+    "RuntimeClass",
+    "IngressClass",
+    "CertificateSigningRequest",
+    "FlowSchema",
+    "PriorityLevelConfiguration",
+    "PodSecurityPolicy",
+];
+
+/// Whether `kind` is a built-in cluster-scoped kind or one of `extra`, the
+/// configured cluster-scoped custom resource kinds.
+pub fn is_cluster_scoped(kind: &str, extra: &[String]) -> bool {
+    CLUSTER_SCOPED_KINDS.contains(&kind) || extra.iter().any(|k| k == kind)
+}
+
+/// Whether `doc` is a custom resource, i.e. its API group is neither a
+/// built-in one (`apps`, `batch`) nor under `k8s.io`.
+fn is_custom_resource(doc: &Value) -> bool {
+    let api_version = doc.get("apiVersion").and_then(Value::as_str).unwrap_or_default();
+    api_version
+        .split_once('/')
+        .is_some_and(|(group, _)| group.contains('.') && !group.ends_with(".k8s.io") && group != "k8s.io")
+}
+
+/// Flags namespaced resources that would land in the `default` namespace.
+/// Teams that set the namespace with kustomize or `kubectl -n` can allow
+/// it to be left unset. Custom resources may be cluster-scoped, so unless
+/// listed as such they are only flagged for an explicit `default`.
+pub struct DefaultNamespaceRule {
+    allow_unset: bool,
+    cluster_scoped_kinds: Vec<String>,
+}
+
+impl DefaultNamespaceRule {
+    pub fn new(allow_unset: bool, cluster_scoped_kinds: Vec<String>) -> Self {
+        DefaultNamespaceRule { allow_unset, cluster_scoped_kinds }
+    }
+}
+
+impl LintRule for DefaultNamespaceRule {
+    fn id(&self) -> &'static str {
+        "default-namespace"
+    }
+
+    fn check(&self, doc: &Value) -> Vec<Violation> {
+        let Some(kind) = doc.get("kind").and_then(Value::as_str) else { return vec![] };
+        if is_cluster_scoped(kind, &self.cluster_scoped_kinds) {
+            return vec![];
+        }
+
+        match doc.get("metadata").and_then(|m| m.get("namespace")).and_then(Value::as_str) {
+            Some("default") => vec![Violation::new("Resource is in the default namespace.", "metadata.namespace")],
+            None if !self.allow_unset && !is_custom_resource(doc) => vec![Violation::new(
+                "Resource does not set metadata.namespace and will be created in the default namespace.",
+                "metadata.namespace",
+            )],
+            _ => vec![],
+        }
+    }
+}
+
+/// Flags cluster-scoped kinds that set `metadata.namespace`, which the API
+/// server ignores and which suggests the resource is in the wrong place.
+pub struct ClusterScopedNamespaceRule {
+    cluster_scoped_kinds: Vec<String>,
+}
+
+impl ClusterScopedNamespaceRule {
+    pub fn new(cluster_scoped_kinds: Vec<String>) -> Self {
+        ClusterScopedNamespaceRule { cluster_scoped_kinds }
+    }
+}
+
+impl LintRule for ClusterScopedNamespaceRule {
+    fn id(&self) -> &'static str {
+        "cluster-scoped-namespace"
+    }
+
+    fn check(&self, doc: &Value) -> Vec<Violation> {
+        let Some(kind) = doc.get("kind").and_then(Value::as_str) else { return vec![] };
+        let namespace = doc.get("metadata").and_then(|m| m.get("namespace")).and_then(Value::as_str);
+        match namespace {
+            Some(namespace) if is_cluster_scoped(kind, &self.cluster_scoped_kinds) => vec![Violation::new(
+                format!("{} is cluster-scoped but sets metadata.namespace '{}'.", kind, namespace),
+                "metadata.namespace",
+            )],
+            _ => vec![],
+        }
+    }
+}
+
+/// Flags namespaced kinds in directories the config reserves for
+/// cluster-scoped resources.
+pub struct ClusterDirectoryRule {
+    cluster_paths: Vec<String>,
+    cluster_scoped_kinds: Vec<String>,
+}
+
+impl ClusterDirectoryRule {
+    pub fn new(cluster_paths: Vec<String>, cluster_scoped_kinds: Vec<String>) -> Self {
+        ClusterDirectoryRule { cluster_paths, cluster_scoped_kinds }
+    }
+}
+
+impl LintRule for ClusterDirectoryRule {
+    fn id(&self) -> &'static str {
+        "namespaced-in-cluster-dir"
+    }
+
+    fn applies_to(&self, path: &str) -> bool {
+        self.cluster_paths.iter().any(|pattern| utils::glob_match(pattern, path))
+    }
+
+    fn check(&self, doc: &Value) -> Vec<Violation> {
+        let Some(kind) = doc.get("kind").and_then(Value::as_str) else { return vec![] };
+        if is_cluster_scoped(kind, &self.cluster_scoped_kinds) {
+            return vec![];
+        }
+        vec![Violation::new(
+            format!("{} is namespaced but lives in a directory for cluster-scoped resources.", kind),
+            "kind",
+        )]
+    }
+}
+
+/// Flags resources in a namespace that neither has a Namespace manifest in
+/// the input set nor is listed as already existing in the cluster.
+pub struct NamespaceExistsRule {
+    existing: Vec<String>,
+    cluster_scoped_kinds: Vec<String>,
+}
+
+impl NamespaceExistsRule {
+    pub fn new(existing: Vec<String>, cluster_scoped_kinds: Vec<String>) -> Self {
+        NamespaceExistsRule { existing, cluster_scoped_kinds }
+    }
+}
+
+impl LintRule for NamespaceExistsRule {
+    fn id(&self) -> &'static str {
+        "namespace-exists"
+    }
+
+    fn check(&self, doc: &Value) -> Vec<Violation> {
+        self.check_in_set(doc, &ResourceSet::default())
+    }
+
+    fn check_in_set(&self, doc: &Value, set: &ResourceSet<'_>) -> Vec<Violation> {
+        let Some(kind) = doc.get("kind").and_then(Value::as_str) else { return vec![] };
+        let Some(namespace) = metadata_namespace(doc) else { return vec![] };
+        if is_cluster_scoped(kind, &self.cluster_scoped_kinds)
+            || self.existing.iter().any(|existing| existing == namespace)
+            || set.find(&["Namespace"], namespace, None).is_some()
+        {
+            return vec![];
+        }
+        vec![Violation::new(
+            format!("Namespace '{}' has no Namespace manifest and is not listed as existing.", namespace),
+            "metadata.namespace",
+        )]
+    }
+}
@@ -0,0 +1,70 @@
+use serde_yaml::Value;
+
+use super::{selectors, workload, LintRule, ResourceSet, Violation};
+use crate::config::NetworkPolicyConfig;
+
+/// Flags workloads whose pods no NetworkPolicy in the input set selects
+/// for ingress or for egress, leaving that direction open to any pod in
+/// the cluster. Both are warnings unless config makes missing ingress
+/// coverage an error; Jobs and CronJobs are skipped unless configured.
+pub struct NetworkPolicyRule {
+    config: NetworkPolicyConfig,
+}
+
+impl NetworkPolicyRule {
+    pub fn new(config: NetworkPolicyConfig) -> Self {
+        NetworkPolicyRule { config }
+    }
+}
+
+impl LintRule for NetworkPolicyRule {
+    fn id(&self) -> &'static str {
+        "network-policy"
+    }
+
+    fn targets(&self, doc: &Value) -> bool {
+        workload::pod_spec(doc).is_some_and(|pod| self.config.include_batch || !pod.is_batch())
+    }
+
+    fn check(&self, doc: &Value) -> Vec<Violation> {
+        self.check_in_set(doc, &ResourceSet::default())
+    }
+
+    fn check_in_set(&self, doc: &Value, set: &ResourceSet<'_>) -> Vec<Violation> {
+        let Some(pod) = workload::pod_spec(doc).filter(|pod| self.config.include_batch || !pod.is_batch()) else { return vec![] };
+        let policies: Vec<&Value> = set
+            .of_kind(&["NetworkPolicy"])
+            .filter(|policy| {
+                policy.get("spec").and_then(|s| s.get("podSelector")).is_some_and(|selector| selectors::selects_workload(policy, selector, doc))
+            })
+            .collect();
+        let field = format!("{}.labels", pod.metadata_path());
+
+        let mut violations = vec![];
+        if !policies.iter().any(|policy| restricts(policy, "Ingress")) {
+            let message = format!("No NetworkPolicy restricts ingress to the pods of this {}.", pod.kind);
+            violations.push(if self.config.missing_ingress_as_error {
+                Violation::new(message, field.clone())
+            } else {
+                Violation::warning(message, field.clone())
+            });
+        }
+        if !policies.iter().any(|policy| restricts(policy, "Egress")) {
+            violations.push(Violation::warning(
+                format!("No NetworkPolicy restricts egress from the pods of this {}.", pod.kind),
+                field,
+            ));
+        }
+        violations
+    }
+}
+
+/// Whether `policy` applies to `direction` traffic. Without `policyTypes`,
+/// a policy always covers ingress, and egress only if it has egress rules.
+fn restricts(policy: &Value, direction: &str) -> bool {
+    let spec = policy.get("spec");
+    match spec.and_then(|s| s.get("policyTypes")).and_then(Value::as_sequence) {
+        Some(types) => types.iter().any(|t| t.as_str() == Some(direction)),
+        None => direction == "Ingress" || spec.and_then(|s| s.get("egress")).is_some(),
+    }
+}
@@ -0,0 +1,94 @@
+use serde_yaml::Value;
+
+use super::{workload, LintRule, Violation};
+
+const OS_LABEL: &str = "kubernetes.io/os";
+
+/// Pod-level `securityContext` fields Windows pods may not set.
+const LINUX_ONLY_POD_FIELDS: &[&str] = &[
+    "seLinuxOptions", "seccompProfile", "appArmorProfile", "fsGroup", "fsGroupChangePolicy",
+    "sysctls", "runAsUser", "runAsGroup", "supplementalGroups",
+];
+
+/// Container-level `securityContext` fields Windows pods may not set.
+const LINUX_ONLY_CONTAINER_FIELDS: &[&str] = &[
+    "seLinuxOptions", "seccompProfile", "appArmorProfile", "capabilities", "readOnlyRootFilesystem",
+    "privileged", "allowPrivilegeEscalation", "procMount", "runAsUser", "runAsGroup",
+];
+
+/// For clusters with Windows nodes: every pod template must pin its OS with
+/// a `kubernetes.io/os` nodeSelector (or `spec.os.name`), and must not use
+/// security settings that only exist on the other OS.
+pub struct NodeOsSelectorRule {
+    windows_nodes: bool,
+}
+
+impl NodeOsSelectorRule {
+    pub fn new(windows_nodes: bool) -> Self {
+        NodeOsSelectorRule { windows_nodes }
+    }
+}
+
+impl LintRule for NodeOsSelectorRule {
+    fn id(&self) -> &'static str {
+        "node-os-selector"
+    }
+
+    fn targets(&self, doc: &Value) -> bool {
+        self.windows_nodes && workload::pod_spec(doc).is_some()
+    }
+
+    fn check(&self, doc: &Value) -> Vec<Violation> {
+        if !self.windows_nodes {
+            return vec![];
+        }
+        let Some(pod) = workload::pod_spec(doc) else { return vec![] };
+        let (pod_spec, prefix) = (pod.spec, pod.path);
+
+        let selector_os = pod_spec.get("nodeSelector").and_then(|s| s.get(OS_LABEL)).and_then(Value::as_str);
+        let spec_os = pod_spec.get("os").and_then(|os| os.get("name")).and_then(Value::as_str);
+        let Some(os) = selector_os.or(spec_os) else {
+            return vec![Violation::new(
+                format!("Pod template does not set a '{}' nodeSelector; required in clusters with Windows nodes.", OS_LABEL),
+                format!("{}.nodeSelector", prefix),
+            )];
+        };
+
+        let mut violations = vec![];
+        if let (Some(selector_os), Some(spec_os)) = (selector_os, spec_os) {
+            if selector_os != spec_os {
+                violations.push(Violation::new(
+                    format!("nodeSelector '{}: {}' contradicts spec.os.name '{}'.", OS_LABEL, selector_os, spec_os),
+                    format!("{}.os.name", prefix),
+                ));
+            }
+        }
+
+        let mut contexts = vec![(format!("{}.securityContext", prefix), pod_spec.get("securityContext"), LINUX_ONLY_POD_FIELDS)];
+        for container in pod.all_containers() {
+            contexts.push((
+                format!("{}.securityContext", container.path),
+                container.value.get("securityContext"),
+                LINUX_ONLY_CONTAINER_FIELDS,
+            ));
+        }
+
+        for (path, context, linux_only) in contexts {
+            let Some(context) = context else { continue };
+            if os == "windows" {
+                for field in linux_only.iter().filter(|field| context.get(**field).is_some()) {
+                    violations.push(Violation::new(
+                        format!("Windows pod sets Linux-only field securityContext.{}.", field),
+                        format!("{}.{}", path, field),
+                    ));
+                }
+            } else if context.get("windowsOptions").is_some() {
+                violations.push(Violation::new(
+                    "Linux pod sets Windows-only field securityContext.windowsOptions.",
+                    format!("{}.windowsOptions", path),
+                ));
+            }
+        }
+        violations
+    }
+}
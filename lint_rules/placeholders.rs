@@ -0,0 +1,94 @@
+use serde_yaml::Value;
+
+use super::{LintRule, Violation};
+
+/// Placeholder markers, matched case-insensitively.
+const MARKERS: &[&str] = &["REPLACE_ME", "REPLACEME", "CHANGEME", "CHANGE_ME"];
+
+/// Helm objects and functions whose actions only make sense before
+/// rendering; Go templates over other data (`{{ .Labels.x }}` in alerting
+/// rules) are legitimate.
+const HELM_ACTIONS: &[&str] = &[".Values", ".Release", ".Chart", ".Capabilities", ".Files", ".Template", "include ", "template ", "tpl "];
+
+/// Flags values left unrendered: Helm actions such as `{{ .Values.x }}`,
+/// envsubst-style `${VAR}` references and placeholder strings such as
+/// `REPLACE_ME`, `CHANGEME` or `TODO-`. Files with actions that break the
+/// YAML never get here; they are skipped as templated. Container commands
+/// and ConfigMap data legitimately hold shell and config syntax, so
+/// `${VAR}` is allowed there.
+pub struct UnrenderedPlaceholderRule;
+
+impl LintRule for UnrenderedPlaceholderRule {
+    fn id(&self) -> &'static str {
+        "unrendered-placeholder"
+    }
+
+    fn check(&self, doc: &Value) -> Vec<Violation> {
+        let kind = doc.get("kind").and_then(Value::as_str).unwrap_or_default();
+        let mut violations = vec![];
+        visit_strings(doc, String::new(), &mut |path, value| {
+            let allows_variables = (kind == "ConfigMap" && (path.starts_with("data.") || path.starts_with("binaryData.")))
+                || path.contains(".command[")
+                || path.contains(".args[");
+            if let Some(placeholder) = placeholder(value, !allows_variables) {
+                violations.push(Violation::new(format!("Value contains the unrendered placeholder '{}'.", placeholder), path));
+            }
+        });
+        violations
+    }
+}
+
+/// Calls `visit` with the dotted path and value of every string scalar.
+fn visit_strings(value: &Value, path: String, visit: &mut impl FnMut(String, &str)) {
+    match value {
+        Value::String(s) => visit(path, s),
+        Value::Mapping(mapping) => {
+            for (key, value) in mapping {
+                let Some(key) = key.as_str() else { continue };
+                let path = if path.is_empty() { key.to_string() } else { format!("{}.{}", path, key) };
+                visit_strings(value, path, visit);
+            }
+        }
+        Value::Sequence(sequence) => {
+            for (i, value) in sequence.iter().enumerate() {
+                visit_strings(value, format!("{}[{}]", path, i), visit);
+            }
+        }
+        Value::Tagged(tagged) => visit_strings(&tagged.value, path, visit),
+        _ => {}
+    }
+}
+
+/// The first placeholder in `value`, checking `${VAR}` references only when
+/// `variables` is set.
+fn placeholder(value: &str, variables: bool) -> Option<String> {
+    let upper = value.to_ascii_uppercase();
+    if let Some((start, len)) = MARKERS.iter().find_map(|marker| upper.find(marker).map(|start| (start, marker.len()))) {
+        return Some(value[start..start + len].to_string());
+    }
+    if value.contains("TODO-") {
+        return Some("TODO-".to_string());
+    }
+    if let Some(action) = helm_action(value) {
+        return Some(action.to_string());
+    }
+    if !variables {
+        return None;
+    }
+    value.match_indices("${").find_map(|(start, _)| {
+        let rest = &value[start + 2..];
+        let end = rest.find('}')?;
+        let name = &rest[..end];
+        let is_variable = !name.is_empty() && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+        is_variable.then(|| value[start..start + end + 3].to_string())
+    })
+}
+
+/// The first `{{ ... }}` action in `value` using a Helm object or function.
+fn helm_action(value: &str) -> Option<&str> {
+    value.match_indices("{{").find_map(|(start, _)| {
+        let end = start + value[start..].find("}}")? + 2;
+        let body = value[start + 2..end - 2].trim_matches(|c: char| c == '-' || c.is_whitespace());
+        HELM_ACTIONS.iter().any(|prefix| body.starts_with(prefix)).then(|| &value[start..end])
+    })
+}
@@ -0,0 +1,53 @@
+use serde_yaml::Value;
+
+use super::{metadata_namespace, workload, LintRule, ResourceSet, Violation};
+use crate::config::PriorityClassConfig;
+use crate::utils;
+
+/// Opt-in: workloads in critical namespaces or under critical paths must
+/// set `priorityClassName`, so node-pressure eviction takes batch pods
+/// first. A workload matching either list is critical; the rule is off
+/// while both are empty.
+pub struct PriorityClassRule {
+    config: PriorityClassConfig,
+}
+
+impl PriorityClassRule {
+    pub fn new(config: PriorityClassConfig) -> Self {
+        PriorityClassRule { config }
+    }
+
+    fn is_critical(&self, doc: &Value, path: Option<&str>) -> bool {
+        let PriorityClassConfig { critical_namespaces, critical_paths } = &self.config;
+        critical_namespaces.iter().any(|n| Some(n.as_str()) == metadata_namespace(doc))
+            || path.is_some_and(|path| critical_paths.iter().any(|pattern| utils::glob_match(pattern, path)))
+    }
+}
+
+impl LintRule for PriorityClassRule {
+    fn id(&self) -> &'static str {
+        "priority-class"
+    }
+
+    fn targets(&self, doc: &Value) -> bool {
+        !(self.config.critical_namespaces.is_empty() && self.config.critical_paths.is_empty()) && workload::pod_spec(doc).is_some()
+    }
+
+    fn check(&self, doc: &Value) -> Vec<Violation> {
+        self.check_in_set(doc, &ResourceSet::default())
+    }
+
+    fn check_in_set(&self, doc: &Value, set: &ResourceSet<'_>) -> Vec<Violation> {
+        let Some(pod) = workload::pod_spec(doc) else { return vec![] };
+        if !self.is_critical(doc, set.origin(doc).map(|origin| origin.path)) {
+            return vec![];
+        }
+        if pod.spec.get("priorityClassName").is_some() {
+            return vec![];
+        }
+        vec![Violation::new(
+            "Critical workload does not set priorityClassName and may be evicted before batch workloads.",
+            format!("{}.priorityClassName", pod.path),
+        )]
+    }
+}
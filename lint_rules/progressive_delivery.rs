@@ -0,0 +1,191 @@
+use serde_yaml::Value;
+
+use super::{is_kind, metadata_namespace, LintRule, ResourceSet, Violation};
+
+/// Fields of an Argo Rollouts canary step; each step sets exactly one.
+const CANARY_STEP_FIELDS: &[&str] =
+    &["setWeight", "pause", "analysis", "experiment", "setCanaryScale", "setHeaderRoute", "setMirrorRoute", "plugin"];
+
+/// Validates the strategy of Argo Rollouts `Rollout`s (`argoproj.io`) and
+/// checks that the Services and AnalysisTemplates it names are in the input
+/// set. Pod template rules already cover Rollouts through `spec.template`.
+pub struct ArgoRolloutRule;
+
+impl LintRule for ArgoRolloutRule {
+    fn id(&self) -> &'static str {
+        "argo-rollout"
+    }
+
+    fn targets(&self, doc: &Value) -> bool {
+        is_kind(doc, &["Rollout"]) && in_group(doc, "argoproj.io")
+    }
+
+    fn check(&self, doc: &Value) -> Vec<Violation> {
+        self.check_in_set(doc, &ResourceSet::default())
+    }
+
+    fn check_in_set(&self, doc: &Value, set: &ResourceSet<'_>) -> Vec<Violation> {
+        if doc.get("kind").and_then(Value::as_str) != Some("Rollout") || !in_group(doc, "argoproj.io") {
+            return vec![];
+        }
+        let strategy = doc.get("spec").and_then(|s| s.get("strategy"));
+        let canary = strategy.and_then(|s| s.get("canary"));
+        let blue_green = strategy.and_then(|s| s.get("blueGreen"));
+
+        let mut refs = References::new(set, metadata_namespace(doc));
+        match (canary, blue_green) {
+            (Some(canary), None) => {
+                refs.service(canary.get("canaryService"), "spec.strategy.canary.canaryService");
+                refs.service(canary.get("stableService"), "spec.strategy.canary.stableService");
+                refs.analysis(canary.get("analysis"), "spec.strategy.canary.analysis");
+
+                let steps = canary.get("steps").and_then(Value::as_sequence).into_iter().flatten();
+                for (i, step) in steps.enumerate() {
+                    let path = format!("spec.strategy.canary.steps[{}]", i);
+                    let fields = CANARY_STEP_FIELDS.iter().filter(|field| step.get(**field).is_some()).count();
+                    if fields != 1 {
+                        refs.violations.push(Violation::new(
+                            format!("Canary step must set exactly one of {}.", CANARY_STEP_FIELDS.join(", ")),
+                            path.clone(),
+                        ));
+                    }
+                    if let Some(weight) = step.get("setWeight") {
+                        if weight.as_i64().is_none_or(|w| !(0..=100).contains(&w)) {
+                            refs.violations
+                                .push(Violation::new("setWeight must be between 0 and 100.", format!("{}.setWeight", path)));
+                        }
+                    }
+                    refs.analysis(step.get("analysis"), &format!("{}.analysis", path));
+                }
+            }
+            (None, Some(blue_green)) => {
+                match blue_green.get("activeService") {
+                    Some(service) => refs.service(Some(service), "spec.strategy.blueGreen.activeService"),
+                    None => refs.violations.push(Violation::new(
+                        "blueGreen strategy must set activeService.",
+                        "spec.strategy.blueGreen.activeService",
+                    )),
+                }
+                refs.service(blue_green.get("previewService"), "spec.strategy.blueGreen.previewService");
+                refs.analysis(blue_green.get("prePromotionAnalysis"), "spec.strategy.blueGreen.prePromotionAnalysis");
+                refs.analysis(blue_green.get("postPromotionAnalysis"), "spec.strategy.blueGreen.postPromotionAnalysis");
+            }
+            _ => refs.violations.push(Violation::new(
+                "Rollout strategy must set exactly one of canary or blueGreen.",
+                "spec.strategy",
+            )),
+        }
+        refs.violations
+    }
+}
+
+/// Validates Flagger `Canary`s: the target workload must be in the input
+/// set, and the service and analysis must be configured.
+pub struct FlaggerCanaryRule;
+
+impl LintRule for FlaggerCanaryRule {
+    fn id(&self) -> &'static str {
+        "flagger-canary"
+    }
+
+    fn targets(&self, doc: &Value) -> bool {
+        is_kind(doc, &["Canary"]) && in_group(doc, "flagger.app")
+    }
+
+    fn check(&self, doc: &Value) -> Vec<Violation> {
+        self.check_in_set(doc, &ResourceSet::default())
+    }
+
+    fn check_in_set(&self, doc: &Value, set: &ResourceSet<'_>) -> Vec<Violation> {
+        if doc.get("kind").and_then(Value::as_str) != Some("Canary") || !in_group(doc, "flagger.app") {
+            return vec![];
+        }
+        let spec = doc.get("spec");
+        let mut violations = vec![];
+
+        let target = spec.and_then(|s| s.get("targetRef"));
+        let target_kind = target.and_then(|t| t.get("kind")).and_then(Value::as_str);
+        let target_name = target.and_then(|t| t.get("name")).and_then(Value::as_str);
+        match (target_kind, target_name) {
+            (Some(kind), Some(name)) => {
+                if !set.is_empty() && set.find(&[kind], name, metadata_namespace(doc)).is_none() {
+                    violations.push(Violation::new(
+                        format!("Canary targets {} '{}', which is not in the input set.", kind, name),
+                        "spec.targetRef.name",
+                    ));
+                }
+            }
+            _ => violations.push(Violation::new("Canary must set targetRef kind and name.", "spec.targetRef")),
+        }
+
+        if spec.and_then(|s| s.get("service")).and_then(|s| s.get("port")).is_none() {
+            violations.push(Violation::new("Canary must set service.port.", "spec.service.port"));
+        }
+
+        let Some(analysis) = spec.and_then(|s| s.get("analysis")) else {
+            violations.push(Violation::new("Canary has no analysis configured.", "spec.analysis"));
+            return violations;
+        };
+        for field in ["interval", "threshold"] {
+            if analysis.get(field).is_none() {
+                violations.push(Violation::new(format!("Canary analysis must set {}.", field), format!("spec.analysis.{}", field)));
+            }
+        }
+        let progressive = analysis.get("maxWeight").is_some() && analysis.get("stepWeight").is_some()
+            || analysis.get("stepWeights").is_some();
+        if !progressive && analysis.get("iterations").is_none() {
+            violations.push(Violation::new(
+                "Canary analysis must set maxWeight and stepWeight, stepWeights, or iterations.",
+                "spec.analysis",
+            ));
+        }
+        violations
+    }
+}
+
+/// Whether `doc`'s apiVersion is in API group `group`, so other projects'
+/// `Rollout` or `Canary` kinds are left alone.
+fn in_group(doc: &Value, group: &str) -> bool {
+    doc.get("apiVersion").and_then(Value::as_str).and_then(|v| v.split_once('/')).is_some_and(|(g, _)| g == group)
+}
+
+/// Collects violations for references that do not resolve in the input
+/// set. Nothing is flagged when checking a document on its own.
+struct References<'s, 'a> {
+    set: &'s ResourceSet<'a>,
+    namespace: Option<&'s str>,
+    violations: Vec<Violation>,
+}
+
+impl<'s, 'a> References<'s, 'a> {
+    fn new(set: &'s ResourceSet<'a>, namespace: Option<&'s str>) -> Self {
+        References { set, namespace, violations: vec![] }
+    }
+
+    fn service(&mut self, name: Option<&Value>, path: &str) {
+        let Some(name) = name.and_then(Value::as_str) else { return };
+        if !self.set.is_empty() && self.set.find(&["Service"], name, self.namespace).is_none() {
+            self.violations.push(Violation::new(format!("Service '{}' is not in the input set.", name), path));
+        }
+    }
+
+    /// `templates[].templateName` of an analysis block.
+    fn analysis(&mut self, analysis: Option<&Value>, path: &str) {
+        let templates = analysis.and_then(|a| a.get("templates")).and_then(Value::as_sequence).into_iter().flatten();
+        for (i, template) in templates.enumerate() {
+            let Some(name) = template.get("templateName").and_then(Value::as_str) else { continue };
+            let cluster_scope = template.get("clusterScope").and_then(Value::as_bool) == Some(true);
+            let found = if cluster_scope {
+                self.set.find(&["ClusterAnalysisTemplate"], name, None)
+            } else {
+                self.set.find(&["AnalysisTemplate"], name, self.namespace)
+            };
+            if !self.set.is_empty() && found.is_none() {
+                self.violations.push(Violation::new(
+                    format!("Analysis template '{}' is not in the input set.", name),
+                    format!("{}.templates[{}].templateName", path, i),
+                ));
+            }
+        }
+    }
+}
@@ -0,0 +1,137 @@
+use serde_yaml::Value;
+
+use super::{is_kind, metadata_name, LintRule, Violation};
+use crate::utils;
+
+const ROLE_KINDS: &[&str] = &["Role", "ClusterRole"];
+const BINDING_KINDS: &[&str] = &["RoleBinding", "ClusterRoleBinding"];
+/// Groups holding every user, every service account, or unchecked admin.
+const BROAD_GROUPS: &[&str] = &["system:masters", "system:authenticated", "system:unauthenticated", "system:serviceaccounts"];
+
+/// Flags Role and ClusterRole rules granting `*` verbs, resources or API
+/// groups, which silently extend to anything added to the cluster later.
+pub struct RbacWildcardRule;
+
+impl LintRule for RbacWildcardRule {
+    fn id(&self) -> &'static str {
+        "rbac-wildcards"
+    }
+
+    fn targets(&self, doc: &Value) -> bool {
+        is_kind(doc, ROLE_KINDS)
+    }
+
+    fn check(&self, doc: &Value) -> Vec<Violation> {
+        let Some(kind) = doc.get("kind").and_then(Value::as_str).filter(|kind| ROLE_KINDS.contains(kind)) else {
+            return vec![];
+        };
+
+        let mut violations = vec![];
+        for (i, rule) in rules(doc).enumerate() {
+            for field in ["verbs", "resources", "apiGroups"] {
+                if strings(rule, field).any(|value| value == "*") {
+                    violations.push(Violation::new(
+                        format!("{} grants {}: [\"*\"]; list the {} it needs.", kind, field, field),
+                        format!("rules[{}].{}", i, field),
+                    ));
+                }
+            }
+        }
+        violations
+    }
+}
+
+/// Flags ClusterRoles that can read Secrets in the core API group. Bound
+/// with a ClusterRoleBinding, that is every Secret in the cluster.
+pub struct RbacClusterSecretsRule;
+
+impl LintRule for RbacClusterSecretsRule {
+    fn id(&self) -> &'static str {
+        "rbac-cluster-secrets"
+    }
+
+    fn targets(&self, doc: &Value) -> bool {
+        is_kind(doc, &["ClusterRole"])
+    }
+
+    fn check(&self, doc: &Value) -> Vec<Violation> {
+        if doc.get("kind").and_then(Value::as_str) != Some("ClusterRole") {
+            return vec![];
+        }
+
+        let mut violations = vec![];
+        for (i, rule) in rules(doc).enumerate() {
+            let core_group = strings(rule, "apiGroups").any(|group| group.is_empty() || group == "*");
+            let secrets = strings(rule, "resources").any(|resource| resource == "secrets" || resource == "*");
+            // Resource names narrow access to specific Secrets.
+            let named = rule.get("resourceNames").and_then(Value::as_sequence).is_some_and(|names| !names.is_empty());
+            let reads = strings(rule, "verbs").any(|verb| matches!(verb, "get" | "list" | "watch" | "*"));
+            if core_group && secrets && reads && !named {
+                violations.push(Violation::new(
+                    "ClusterRole can read Secrets; bound cluster-wide it exposes every Secret in every namespace.",
+                    format!("rules[{}].resources", i),
+                ));
+            }
+        }
+        violations
+    }
+}
+
+/// Flags RoleBindings and ClusterRoleBindings that grant `cluster-admin`
+/// or bind broad groups such as `system:authenticated` or
+/// `system:masters`. Bindings whose names match a configured glob are
+/// allowed, for break-glass access.
+pub struct RbacBindingsRule {
+    allowed_bindings: Vec<String>,
+}
+
+impl RbacBindingsRule {
+    pub fn new(allowed_bindings: Vec<String>) -> Self {
+        RbacBindingsRule { allowed_bindings }
+    }
+}
+
+impl LintRule for RbacBindingsRule {
+    fn id(&self) -> &'static str {
+        "rbac-bindings"
+    }
+
+    fn targets(&self, doc: &Value) -> bool {
+        is_kind(doc, BINDING_KINDS)
+    }
+
+    fn check(&self, doc: &Value) -> Vec<Violation> {
+        let Some(kind) = doc.get("kind").and_then(Value::as_str).filter(|kind| BINDING_KINDS.contains(kind)) else {
+            return vec![];
+        };
+        let name = metadata_name(doc).unwrap_or_default();
+        if self.allowed_bindings.iter().any(|pattern| utils::glob_match(pattern, name)) {
+            return vec![];
+        }
+
+        let mut violations = vec![];
+        if doc.get("roleRef").and_then(|r| r.get("name")).and_then(Value::as_str) == Some("cluster-admin") {
+            violations.push(Violation::new(format!("{} grants cluster-admin.", kind), "roleRef.name"));
+        }
+        let subjects = doc.get("subjects").and_then(Value::as_sequence).into_iter().flatten();
+        for (i, subject) in subjects.enumerate() {
+            let group = subject.get("kind").and_then(Value::as_str) == Some("Group");
+            let Some(subject_name) = subject.get("name").and_then(Value::as_str) else { continue };
+            if group && BROAD_GROUPS.contains(&subject_name) {
+                violations.push(Violation::new(
+                    format!("{} binds the broad group '{}'; bind specific users, groups or service accounts.", kind, subject_name),
+                    format!("subjects[{}].name", i),
+                ));
+            }
+        }
+        violations
+    }
+}
+
+fn rules(doc: &Value) -> impl Iterator<Item = &Value> {
+    doc.get("rules").and_then(Value::as_sequence).into_iter().flatten()
+}
+
+fn strings<'a>(rule: &'a Value, field: &str) -> impl Iterator<Item = &'a str> {
+    rule.get(field).and_then(Value::as_sequence).into_iter().flatten().filter_map(Value::as_str)
+}
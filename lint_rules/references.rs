@@ -0,0 +1,189 @@
+use serde_yaml::Value;
+
+use super::{is_kind, metadata_namespace, workload, LintRule, ResourceSet, Violation};
+
+/// A ConfigMap or Secret the pod reads, and the key it reads if any.
+struct Reference<'a> {
+    kind: &'static str,
+    name: &'a str,
+    key: Option<&'a str>,
+    /// `optional: true` references don't stop the pod from starting.
+    optional: bool,
+    field: String,
+}
+
+/// Every ConfigMap and Secret a pod mounts or reads into env, along with
+/// the keys it reads, must be defined in the linted files. References to
+/// resources created elsewhere (sealed or external secrets) can be
+/// downgraded to warnings.
+pub struct ConfigReferencesRule {
+    missing_as_warning: bool,
+}
+
+impl ConfigReferencesRule {
+    pub fn new(missing_as_warning: bool) -> Self {
+        ConfigReferencesRule { missing_as_warning }
+    }
+}
+
+impl LintRule for ConfigReferencesRule {
+    fn id(&self) -> &'static str {
+        "config-references"
+    }
+
+    fn targets(&self, doc: &Value) -> bool {
+        workload::pod_spec(doc).is_some()
+    }
+
+    fn check(&self, doc: &Value) -> Vec<Violation> {
+        self.check_in_set(doc, &ResourceSet::default())
+    }
+
+    fn check_in_set(&self, doc: &Value, set: &ResourceSet<'_>) -> Vec<Violation> {
+        let Some(pod) = workload::pod_spec(doc) else { return vec![] };
+
+        let mut violations = vec![];
+        for reference in references(&pod).into_iter().filter(|r| !r.optional) {
+            let Some(resource) = set.find(&[reference.kind], reference.name, metadata_namespace(doc)) else {
+                let message = format!("{} '{}' is not defined in the linted files.", reference.kind, reference.name);
+                violations.push(if self.missing_as_warning {
+                    Violation::warning(message, reference.field)
+                } else {
+                    Violation::new(message, reference.field)
+                });
+                continue;
+            };
+            let Some(key) = reference.key else { continue };
+            let sections: &[&str] = match reference.kind {
+                "ConfigMap" => &["data", "binaryData"],
+                _ => &["data", "stringData"],
+            };
+            if !sections.iter().any(|section| resource.get(*section).and_then(|data| data.get(key)).is_some()) {
+                violations.push(Violation::new(
+                    format!("{} '{}' has no key '{}'.", reference.kind, reference.name, key),
+                    reference.field,
+                ));
+            }
+        }
+        violations
+    }
+}
+
+/// Every `imagePullSecrets` entry on a pod or ServiceAccount must name a
+/// registry credentials Secret in the linted files; a typo only shows up
+/// as ImagePullBackOff at runtime. Missing Secrets follow the same
+/// `missing-as-warning` setting as `config-references`.
+pub struct ImagePullSecretsRule {
+    missing_as_warning: bool,
+}
+
+impl ImagePullSecretsRule {
+    pub fn new(missing_as_warning: bool) -> Self {
+        ImagePullSecretsRule { missing_as_warning }
+    }
+}
+
+impl LintRule for ImagePullSecretsRule {
+    fn id(&self) -> &'static str {
+        "image-pull-secrets"
+    }
+
+    fn targets(&self, doc: &Value) -> bool {
+        is_kind(doc, &["ServiceAccount"]) || workload::pod_spec(doc).is_some()
+    }
+
+    fn check(&self, doc: &Value) -> Vec<Violation> {
+        self.check_in_set(doc, &ResourceSet::default())
+    }
+
+    fn check_in_set(&self, doc: &Value, set: &ResourceSet<'_>) -> Vec<Violation> {
+        let (secrets, path) = if is_kind(doc, &["ServiceAccount"]) {
+            (doc.get("imagePullSecrets"), "imagePullSecrets".to_string())
+        } else if let Some(pod) = workload::pod_spec(doc) {
+            (pod.spec.get("imagePullSecrets"), format!("{}.imagePullSecrets", pod.path))
+        } else {
+            return vec![];
+        };
+
+        let mut violations = vec![];
+        for (i, secret) in secrets.and_then(Value::as_sequence).into_iter().flatten().enumerate() {
+            let Some(name) = secret.get("name").and_then(Value::as_str) else { continue };
+            let field = format!("{}[{}].name", path, i);
+            let Some(resource) = set.find(&["Secret"], name, metadata_namespace(doc)) else {
+                let message = format!("Image pull Secret '{}' is not defined in the linted files.", name);
+                violations.push(if self.missing_as_warning {
+                    Violation::warning(message, field)
+                } else {
+                    Violation::new(message, field)
+                });
+                continue;
+            };
+            // `dockercfg` is the legacy format, still accepted by kubelets.
+            let secret_type = resource.get("type").and_then(Value::as_str).unwrap_or("Opaque");
+            if !matches!(secret_type, "kubernetes.io/dockerconfigjson" | "kubernetes.io/dockercfg") {
+                violations.push(Violation::new(
+                    format!("Image pull Secret '{}' has type {}; use kubernetes.io/dockerconfigjson.", name, secret_type),
+                    field,
+                ));
+            }
+        }
+        violations
+    }
+}
+
+fn references<'a>(pod: &workload::PodSpec<'a>) -> Vec<Reference<'a>> {
+    let mut references = vec![];
+    let mut add = |kind: &'static str, source: Option<&'a Value>, name_key: &str, key: Option<&'a Value>, field: String| {
+        let Some(source) = source else { return };
+        let Some(name) = source.get(name_key).and_then(Value::as_str) else { return };
+        let optional = source.get("optional").and_then(Value::as_bool) == Some(true);
+        references.push(Reference { kind, name, key: key.and_then(Value::as_str), optional, field });
+    };
+    // Volumes with `items` read only the listed keys.
+    let items = |source: Option<&'a Value>| -> Vec<Option<&'a Value>> {
+        match source.and_then(|s| s.get("items")).and_then(Value::as_sequence) {
+            Some(items) => items.iter().map(|item| item.get("key")).collect(),
+            None => vec![None],
+        }
+    };
+
+    let volumes = pod.spec.get("volumes").and_then(Value::as_sequence).into_iter().flatten();
+    for (i, volume) in volumes.enumerate() {
+        let path = format!("{}.volumes[{}]", pod.path, i);
+        for key in items(volume.get("configMap")) {
+            add("ConfigMap", volume.get("configMap"), "name", key, format!("{}.configMap.name", path));
+        }
+        for key in items(volume.get("secret")) {
+            add("Secret", volume.get("secret"), "secretName", key, format!("{}.secret.secretName", path));
+        }
+        let sources = volume.get("projected").and_then(|p| p.get("sources")).and_then(Value::as_sequence);
+        for (j, source) in sources.into_iter().flatten().enumerate() {
+            let path = format!("{}.projected.sources[{}]", path, j);
+            for key in items(source.get("configMap")) {
+                add("ConfigMap", source.get("configMap"), "name", key, format!("{}.configMap.name", path));
+            }
+            for key in items(source.get("secret")) {
+                add("Secret", source.get("secret"), "name", key, format!("{}.secret.name", path));
+            }
+        }
+    }
+
+    for container in pod.all_containers() {
+        let env_from = container.value.get("envFrom").and_then(Value::as_sequence).into_iter().flatten();
+        for (j, env_from) in env_from.enumerate() {
+            let path = format!("{}.envFrom[{}]", container.path, j);
+            add("ConfigMap", env_from.get("configMapRef"), "name", None, format!("{}.configMapRef.name", path));
+            add("Secret", env_from.get("secretRef"), "name", None, format!("{}.secretRef.name", path));
+        }
+        let env = container.value.get("env").and_then(Value::as_sequence).into_iter().flatten();
+        for (j, env) in env.enumerate() {
+            let path = format!("{}.env[{}].valueFrom", container.path, j);
+            let value_from = env.get("valueFrom");
+            let config_map = value_from.and_then(|v| v.get("configMapKeyRef"));
+            add("ConfigMap", config_map, "name", config_map.and_then(|c| c.get("key")), format!("{}.configMapKeyRef.name", path));
+            let secret = value_from.and_then(|v| v.get("secretKeyRef"));
+            add("Secret", secret, "name", secret.and_then(|s| s.get("key")), format!("{}.secretKeyRef.name", path));
+        }
+    }
+    references
+}
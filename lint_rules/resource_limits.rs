@@ -1,23 +1,125 @@
 use serde_yaml::Value;
 
-use super::LintRule;
+use super::workload::{self, ContainerKind};
+use super::{LintRule, Violation};
+use crate::config::CpuLimitsPolicy;
+use crate::quantity::Quantity;
 
 pub struct ResourceLimitsRule;
 
 impl LintRule for ResourceLimitsRule {
-    fn check(&self, doc: &Value) -> Option<String> {
-        let containers = doc
-            .get("spec")?
-            .get("template")?
-            .get("spec")?
-            .get("containers")?
-            .as_sequence()?;
-
-        for container in containers {
-            if container.get("resources").and_then(|r| r.get("limits")).is_none() {
-                return Some("Container is missing resource limits.".to_string());
+    fn id(&self) -> &'static str {
+        "resource-limits"
+    }
+
+    fn targets(&self, doc: &Value) -> bool {
+        workload::pod_spec(doc).is_some()
+    }
+
+    fn check(&self, doc: &Value) -> Vec<Violation> {
+        let Some(pod) = workload::pod_spec(doc) else { return vec![] };
+
+        let mut violations = vec![];
+        // Ephemeral containers may not declare resources.
+        for container in pod.all_containers().into_iter().filter(|c| c.kind != ContainerKind::Ephemeral) {
+            if container.value.get("resources").and_then(|r| r.get("limits")).is_none() {
+                violations.push(Violation::new(
+                    format!("{} is missing resource limits.", container.label()),
+                    format!("{}.resources.limits", container.path),
+                ));
+            }
+        }
+        violations
+    }
+}
+
+/// Flags containers whose memory limit differs from their memory request,
+/// which leaves them open to OOM kills under node pressure. With
+/// `require_guaranteed`, CPU and memory must both have equal requests and
+/// limits so the pod gets the Guaranteed QoS class.
+pub struct QosRule {
+    require_guaranteed: bool,
+}
+
+impl QosRule {
+    pub fn new(require_guaranteed: bool) -> Self {
+        QosRule { require_guaranteed }
+    }
+}
+
+impl LintRule for QosRule {
+    fn id(&self) -> &'static str {
+        "qos"
+    }
+
+    fn targets(&self, doc: &Value) -> bool {
+        workload::pod_spec(doc).is_some()
+    }
+
+    fn check(&self, doc: &Value) -> Vec<Violation> {
+        let Some(pod) = workload::pod_spec(doc) else { return vec![] };
+        let resources: &[&str] = if self.require_guaranteed { &["cpu", "memory"] } else { &["memory"] };
+
+        let mut violations = vec![];
+        for container in pod.all_containers().into_iter().filter(|c| c.kind != ContainerKind::Ephemeral) {
+            let spec = container.value.get("resources");
+            for resource in resources {
+                let quantity = |field: &str| spec.and_then(|s| s.get(field)).and_then(|f| f.get(*resource)).and_then(Quantity::from_value);
+                // An unset request defaults to the limit.
+                let (request, limit) = (quantity("requests"), quantity("limits"));
+                let message = match (request, limit) {
+                    (_, None) if self.require_guaranteed || request.is_some() => {
+                        format!("{} sets no {} limit.", container.label(), resource)
+                    }
+                    (Some(request), Some(limit)) if request != limit => {
+                        format!("{} {} request differs from its limit.", container.label(), resource)
+                    }
+                    _ => continue,
+                };
+                violations.push(Violation::new(message, format!("{}.resources.limits.{}", container.path, resource)));
             }
         }
-        None
+        violations
+    }
+}
+
+/// Enforces the configured stance on CPU limits: some orgs forbid them to
+/// avoid CFS throttling, others require them for fair sharing.
+pub struct CpuLimitsRule {
+    policy: CpuLimitsPolicy,
+}
+
+impl CpuLimitsRule {
+    pub fn new(policy: CpuLimitsPolicy) -> Self {
+        CpuLimitsRule { policy }
+    }
+}
+
+impl LintRule for CpuLimitsRule {
+    fn id(&self) -> &'static str {
+        "cpu-limits"
+    }
+
+    fn targets(&self, doc: &Value) -> bool {
+        self.policy != CpuLimitsPolicy::Ignore && workload::pod_spec(doc).is_some()
+    }
+
+    fn check(&self, doc: &Value) -> Vec<Violation> {
+        let Some(pod) = workload::pod_spec(doc) else { return vec![] };
+
+        let mut violations = vec![];
+        for container in pod.all_containers().into_iter().filter(|c| c.kind != ContainerKind::Ephemeral) {
+            let has_limit = container.value.get("resources").and_then(|r| r.get("limits")).and_then(|l| l.get("cpu")).is_some();
+            let message = match (self.policy, has_limit) {
+                (CpuLimitsPolicy::Forbid, true) => "sets a CPU limit, which can cause throttling",
+                (CpuLimitsPolicy::Require, false) => "is missing a CPU limit",
+                _ => continue,
+            };
+            violations.push(Violation::new(
+                format!("{} {}.", container.label(), message),
+                format!("{}.resources.limits.cpu", container.path),
+            ));
+        }
+        violations
     }
 }
@@ -0,0 +1,78 @@
+use serde_yaml::Value;
+use std::collections::BTreeMap;
+
+use super::{is_kind, LintRule, Violation};
+
+/// Deployments carrying the configured zero-downtime labels must roll out
+/// with `maxUnavailable: 0` and `maxSurge >= 1`, never `Recreate`.
+pub struct ZeroDowntimeStrategyRule {
+    selector: BTreeMap<String, String>,
+}
+
+impl ZeroDowntimeStrategyRule {
+    pub fn new(selector: BTreeMap<String, String>) -> Self {
+        ZeroDowntimeStrategyRule { selector }
+    }
+
+    fn is_zero_downtime(&self, doc: &Value) -> bool {
+        let Some(labels) = doc.get("metadata").and_then(|m| m.get("labels")) else { return false };
+        !self.selector.is_empty()
+            && self
+                .selector
+                .iter()
+                .all(|(key, value)| labels.get(key.as_str()).and_then(Value::as_str) == Some(value.as_str()))
+    }
+}
+
+impl LintRule for ZeroDowntimeStrategyRule {
+    fn id(&self) -> &'static str {
+        "zero-downtime-strategy"
+    }
+
+    fn targets(&self, doc: &Value) -> bool {
+        is_kind(doc, &["Deployment"])
+    }
+
+    fn check(&self, doc: &Value) -> Vec<Violation> {
+        if doc.get("kind").and_then(Value::as_str) != Some("Deployment") || !self.is_zero_downtime(doc) {
+            return vec![];
+        }
+
+        let strategy = doc.get("spec").and_then(|spec| spec.get("strategy"));
+        if strategy.and_then(|s| s.get("type")).and_then(Value::as_str) == Some("Recreate") {
+            return vec![Violation::new(
+                "Zero-downtime workload uses the Recreate strategy, which stops all pods before starting new ones.",
+                "spec.strategy.type",
+            )];
+        }
+
+        // Kubernetes defaults both to 25% when unset.
+        let rolling_update = strategy.and_then(|s| s.get("rollingUpdate"));
+        let max_unavailable = rolling_update.and_then(|r| r.get("maxUnavailable"));
+        let max_surge = rolling_update.and_then(|r| r.get("maxSurge"));
+
+        let mut violations = vec![];
+        if max_unavailable.is_none_or(|v| int_or_percent(v) != Some(0)) {
+            violations.push(Violation::new(
+                "Zero-downtime workload must set rollingUpdate.maxUnavailable: 0.",
+                "spec.strategy.rollingUpdate.maxUnavailable",
+            ));
+        }
+        if max_surge.is_some_and(|v| int_or_percent(v).is_none_or(|surge| surge < 1)) {
+            violations.push(Violation::new(
+                "Zero-downtime workload must set rollingUpdate.maxSurge to at least 1.",
+                "spec.strategy.rollingUpdate.maxSurge",
+            ));
+        }
+        violations
+    }
+}
+
+/// Value of an IntOrString field such as `1` or `"25%"`.
+fn int_or_percent(value: &Value) -> Option<i64> {
+    match value {
+        Value::Number(n) => n.as_i64(),
+        Value::String(s) => s.trim_end_matches('%').parse().ok(),
+        _ => None,
+    }
+}
@@ -0,0 +1,60 @@
+use serde_yaml::Value;
+
+use super::{workload, LintRule, Violation};
+use crate::config::SampleManifestsConfig;
+use crate::utils;
+
+/// Flags resources that look like copied samples (names such as `foo` or
+/// `example-app`, or well-known demo images on an unlabeled resource) when
+/// they live under a production path.
+pub struct SampleManifestRule {
+    config: SampleManifestsConfig,
+}
+
+impl SampleManifestRule {
+    pub fn new(config: SampleManifestsConfig) -> Self {
+        SampleManifestRule { config }
+    }
+}
+
+impl LintRule for SampleManifestRule {
+    fn id(&self) -> &'static str {
+        "sample-manifest"
+    }
+
+    fn applies_to(&self, path: &str) -> bool {
+        self.config.production_paths.iter().any(|pattern| utils::glob_match(pattern, path))
+    }
+
+    fn check(&self, doc: &Value) -> Vec<Violation> {
+        let metadata = doc.get("metadata");
+        if let Some(name) = metadata.and_then(|m| m.get("name")).and_then(Value::as_str) {
+            let lowercase = name.to_lowercase();
+            if let Some(pattern) = self.config.name_patterns.iter().find(|p| utils::glob_match(p, &lowercase)) {
+                return vec![Violation::new(
+                    format!("Resource name '{}' matches sample pattern '{}' in a production path.", name, pattern),
+                    "metadata.name",
+                )];
+            }
+        }
+
+        if metadata.and_then(|m| m.get("labels")).is_some() {
+            return vec![];
+        }
+
+        let Some(pod) = workload::pod_spec(doc) else { return vec![] };
+
+        let mut violations = vec![];
+        for container in pod.all_containers() {
+            if let Some(image) = container.value.get("image").and_then(Value::as_str) {
+                if self.config.sample_images.iter().any(|pattern| utils::glob_match(pattern, image)) {
+                    violations.push(Violation::new(
+                        format!("Unlabeled resource uses sample image '{}' in a production path.", image),
+                        format!("{}.image", container.path),
+                    ));
+                }
+            }
+        }
+        violations
+    }
+}
@@ -1,47 +1,276 @@
 use serde_yaml::Value;
 
-use super::LintRule;
+use super::{workload, LintRule, Violation};
 
 pub struct RunAsNonRootRule;
 
 impl LintRule for RunAsNonRootRule {
-    fn check(&self, doc: &Value) -> Option<String> {
-        let containers = doc
-            .get("spec")?
-            .get("template")?
-            .get("spec")?
-            .get("containers")?
-            .as_sequence()?;
-
-        for container in containers {
-            if let Some(security_context) = container.get("securityContext") {
-                if security_context.get("runAsNonRoot").is_none() {
-                    return Some("Container does not have runAsNonRoot set.".to_string());
-                }
-            }
+    fn id(&self) -> &'static str {
+        "run-as-non-root"
+    }
+
+    fn targets(&self, doc: &Value) -> bool {
+        workload::pod_spec(doc).is_some()
+    }
+
+    fn check(&self, doc: &Value) -> Vec<Violation> {
+        let Some(pod) = workload::pod_spec(doc) else { return vec![] };
+        let containers = pod.all_containers();
+
+        let missing: Vec<_> = containers
+            .iter()
+            .filter(|container| pod.effective_security_setting(container, "runAsNonRoot").is_none())
+            .collect();
+
+        // One pod-level finding when no container sets it, rather than
+        // repeating the same problem for every container.
+        if !missing.is_empty() && missing.len() == containers.len() {
+            return vec![Violation::new(
+                "Pod does not have runAsNonRoot set.",
+                format!("{}.securityContext.runAsNonRoot", pod.path),
+            )];
         }
-        None
+
+        missing
+            .into_iter()
+            .map(|container| {
+                Violation::new(
+                    format!("{} does not have runAsNonRoot set.", container.label()),
+                    format!("{}.securityContext.runAsNonRoot", container.path),
+                )
+            })
+            .collect()
     }
 }
 
 pub struct ReadOnlyRootFilesystemRule;
 
 impl LintRule for ReadOnlyRootFilesystemRule {
-    fn check(&self, doc: &Value) -> Option<String> {
-        let containers = doc
-            .get("spec")?
-            .get("template")?
-            .get("spec")?
-            .get("containers")?
-            .as_sequence()?;
-
-        for container in containers {
-            if let Some(security_context) = container.get("securityContext") {
-                if security_context.get("readOnlyRootFilesystem").is_none() {
-                    return Some("Container does not have readOnlyRootFilesystem set.".to_string());
+    fn id(&self) -> &'static str {
+        "read-only-root-filesystem"
+    }
+
+    fn targets(&self, doc: &Value) -> bool {
+        workload::pod_spec(doc).is_some()
+    }
+
+    fn check(&self, doc: &Value) -> Vec<Violation> {
+        let Some(pod) = workload::pod_spec(doc) else { return vec![] };
+
+        let mut violations = vec![];
+        for container in pod.all_containers() {
+            // Container-only setting; there is no pod-level equivalent.
+            let security_context = container.value.get("securityContext");
+            if security_context.and_then(|sc| sc.get("readOnlyRootFilesystem")).is_none() {
+                violations.push(Violation::new(
+                    format!("{} does not have readOnlyRootFilesystem set.", container.label()),
+                    format!("{}.securityContext.readOnlyRootFilesystem", container.path),
+                ));
+            }
+        }
+        violations
+    }
+}
+
+/// For containers with a read-only root filesystem, flags commonly written
+/// paths (logs, temp files) that have no writable volume mounted over them,
+/// where the app would fail at runtime.
+pub struct WritablePathsRule {
+    paths: Vec<String>,
+}
+
+impl WritablePathsRule {
+    pub fn new(paths: Vec<String>) -> Self {
+        WritablePathsRule { paths }
+    }
+}
+
+impl LintRule for WritablePathsRule {
+    fn id(&self) -> &'static str {
+        "writable-paths"
+    }
+
+    fn targets(&self, doc: &Value) -> bool {
+        workload::pod_spec(doc).is_some()
+    }
+
+    fn check(&self, doc: &Value) -> Vec<Violation> {
+        let Some(pod) = workload::pod_spec(doc) else { return vec![] };
+
+        let mut violations = vec![];
+        for container in pod.all_containers() {
+            let read_only = container.value.get("securityContext").and_then(|sc| sc.get("readOnlyRootFilesystem"));
+            if read_only.and_then(Value::as_bool) != Some(true) {
+                continue;
+            }
+
+            let mounts = container.value.get("volumeMounts").and_then(Value::as_sequence);
+            let writable_mounts: Vec<&str> = mounts
+                .into_iter()
+                .flatten()
+                .filter(|mount| mount.get("readOnly").and_then(Value::as_bool) != Some(true))
+                .filter_map(|mount| mount.get("mountPath").and_then(Value::as_str))
+                .collect();
+
+            for path in &self.paths {
+                let covered = writable_mounts.iter().any(|mount| {
+                    let mount = mount.trim_end_matches('/');
+                    path == mount || path.strip_prefix(mount).is_some_and(|rest| rest.starts_with('/'))
+                });
+                if !covered {
+                    violations.push(Violation::new(
+                        format!(
+                            "{} has a read-only root filesystem but no writable volume at {}; mount an emptyDir there.",
+                            container.label(),
+                            path
+                        ),
+                        format!("{}.volumeMounts", container.path),
+                    ));
                 }
             }
         }
-        None
+        violations
+    }
+}
+
+pub struct AllowPrivilegeEscalationRule;
+
+impl LintRule for AllowPrivilegeEscalationRule {
+    fn id(&self) -> &'static str {
+        "allow-privilege-escalation"
+    }
+
+    fn targets(&self, doc: &Value) -> bool {
+        workload::pod_spec(doc).is_some()
     }
+
+    fn check(&self, doc: &Value) -> Vec<Violation> {
+        let Some(pod) = workload::pod_spec(doc) else { return vec![] };
+
+        let mut violations = vec![];
+        for container in pod.all_containers() {
+            // Container-only setting; there is no pod-level equivalent.
+            let setting = container
+                .value
+                .get("securityContext")
+                .and_then(|sc| sc.get("allowPrivilegeEscalation"))
+                .and_then(Value::as_bool);
+            let message = match setting {
+                Some(false) => continue,
+                Some(true) => "allows privilege escalation",
+                None => "does not set allowPrivilegeEscalation to false",
+            };
+            violations.push(Violation::new(
+                format!("{} {}.", container.label(), message),
+                format!("{}.securityContext.allowPrivilegeEscalation", container.path),
+            ));
+        }
+        violations
+    }
+}
+
+/// Requires a `RuntimeDefault` or `Localhost` seccomp profile for every
+/// container, set on the container or inherited from the pod.
+pub struct SeccompProfileRule;
+
+impl LintRule for SeccompProfileRule {
+    fn id(&self) -> &'static str {
+        "seccomp-profile"
+    }
+
+    fn targets(&self, doc: &Value) -> bool {
+        workload::pod_spec(doc).is_some()
+    }
+
+    fn check(&self, doc: &Value) -> Vec<Violation> {
+        let Some(pod) = workload::pod_spec(doc) else { return vec![] };
+        let containers = pod.all_containers();
+
+        let unconfined: Vec<_> = containers
+            .iter()
+            .filter(|container| {
+                let profile = pod.effective_security_setting(container, "seccompProfile");
+                let profile_type = profile.and_then(|p| p.get("type")).and_then(Value::as_str);
+                !matches!(profile_type, Some("RuntimeDefault" | "Localhost"))
+            })
+            .collect();
+
+        // One pod-level finding when no container is covered, rather than
+        // repeating the same problem for every container.
+        if !unconfined.is_empty() && unconfined.len() == containers.len() {
+            return vec![Violation::new(
+                "Pod does not set seccompProfile type RuntimeDefault or Localhost.",
+                format!("{}.securityContext.seccompProfile.type", pod.path),
+            )];
+        }
+
+        unconfined
+            .into_iter()
+            .map(|container| {
+                Violation::new(
+                    format!("{} does not set seccompProfile type RuntimeDefault or Localhost.", container.label()),
+                    format!("{}.securityContext.seccompProfile.type", container.path),
+                )
+            })
+            .collect()
+    }
+}
+
+/// Requires every container to drop all capabilities and flags any
+/// dangerous capability added back.
+pub struct CapabilitiesRule {
+    dangerous: Vec<String>,
+}
+
+impl CapabilitiesRule {
+    pub fn new(dangerous: Vec<String>) -> Self {
+        CapabilitiesRule { dangerous: dangerous.iter().map(|c| normalize_capability(c)).collect() }
+    }
+}
+
+impl LintRule for CapabilitiesRule {
+    fn id(&self) -> &'static str {
+        "capabilities"
+    }
+
+    fn targets(&self, doc: &Value) -> bool {
+        workload::pod_spec(doc).is_some()
+    }
+
+    fn check(&self, doc: &Value) -> Vec<Violation> {
+        let Some(pod) = workload::pod_spec(doc) else { return vec![] };
+
+        let mut violations = vec![];
+        for container in pod.all_containers() {
+            // Container-only setting; there is no pod-level equivalent.
+            let capabilities = container.value.get("securityContext").and_then(|sc| sc.get("capabilities"));
+            let list = |field| capabilities.and_then(|c| c.get(field)).and_then(Value::as_sequence);
+
+            let drops_all = list("drop")
+                .is_some_and(|drop| drop.iter().filter_map(Value::as_str).any(|c| normalize_capability(c) == "ALL"));
+            if !drops_all {
+                violations.push(Violation::new(
+                    format!("{} does not drop ALL capabilities.", container.label()),
+                    format!("{}.securityContext.capabilities.drop", container.path),
+                ));
+            }
+
+            for (i, capability) in list("add").into_iter().flatten().enumerate() {
+                let Some(capability) = capability.as_str() else { continue };
+                if self.dangerous.contains(&normalize_capability(capability)) {
+                    violations.push(Violation::new(
+                        format!("{} adds dangerous capability {}.", container.label(), capability),
+                        format!("{}.securityContext.capabilities.add[{}]", container.path, i),
+                    ));
+                }
+            }
+        }
+        violations
+    }
+}
+
+/// `cap_net_raw` and `NET_RAW` name the same capability.
+fn normalize_capability(capability: &str) -> String {
+    let capability = capability.to_ascii_uppercase();
+    capability.strip_prefix("CAP_").map(str::to_string).unwrap_or(capability)
 }
@@ -0,0 +1,44 @@
+use serde_yaml::Value;
+
+use super::{metadata_namespace, same_namespace, workload};
+
+/// Labels on the pods a workload creates, from its pod template or, for a
+/// bare Pod, its own metadata.
+pub fn pod_labels(workload: &Value) -> Option<&Value> {
+    let pod = workload::pod_spec(workload)?;
+    pod.metadata_path().split('.').try_fold(workload, |value, key| value.get(key))?.get("labels")
+}
+
+/// Whether a label selector (`matchLabels` and `matchExpressions`) selects
+/// pods with `labels`. An empty selector selects everything.
+pub fn selector_matches(selector: &Value, labels: &Value) -> bool {
+    let label = |key: &str| labels.get(key).and_then(Value::as_str);
+
+    let mut match_labels = selector.get("matchLabels").and_then(Value::as_mapping).into_iter().flatten();
+    let labels_match = match_labels.all(|(key, value)| key.as_str().and_then(label).is_some_and(|v| Some(v) == value.as_str()));
+
+    let mut expressions = selector.get("matchExpressions").and_then(Value::as_sequence).into_iter().flatten();
+    let expressions_match = expressions.all(|expression| {
+        let Some(key) = expression.get("key").and_then(Value::as_str) else { return false };
+        let values: Vec<&str> =
+            expression.get("values").and_then(Value::as_sequence).into_iter().flatten().filter_map(Value::as_str).collect();
+        match expression.get("operator").and_then(Value::as_str) {
+            Some("In") => label(key).is_some_and(|v| values.contains(&v)),
+            Some("NotIn") => label(key).is_none_or(|v| !values.contains(&v)),
+            Some("Exists") => label(key).is_some(),
+            Some("DoesNotExist") => label(key).is_none(),
+            _ => false,
+        }
+    });
+
+    labels_match && expressions_match
+}
+
+/// Whether `selector`, found on `owner` (a PodDisruptionBudget,
+/// NetworkPolicy and the like), selects the pods of `workload`. Selectors
+/// only reach pods in their owner's namespace.
+pub fn selects_workload(owner: &Value, selector: &Value, workload: &Value) -> bool {
+    same_namespace(metadata_namespace(owner), metadata_namespace(workload))
+        && workload::pod_spec(workload).is_some()
+        && selector_matches(selector, pod_labels(workload).unwrap_or(&Value::Null))
+}
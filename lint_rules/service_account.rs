@@ -0,0 +1,80 @@
+use serde_yaml::Value;
+
+use super::{metadata_namespace, same_namespace, workload, LintRule, ResourceSet, Violation};
+
+/// Flags workloads that mount a ServiceAccount token they have no use for:
+/// `automountServiceAccountToken` is not disabled, and no RoleBinding or
+/// ClusterRoleBinding in the input set grants their ServiceAccount anything.
+pub struct AutomountServiceAccountTokenRule;
+
+impl LintRule for AutomountServiceAccountTokenRule {
+    fn id(&self) -> &'static str {
+        "automount-service-account-token"
+    }
+
+    fn targets(&self, doc: &Value) -> bool {
+        workload::pod_spec(doc).is_some()
+    }
+
+    fn check(&self, doc: &Value) -> Vec<Violation> {
+        self.check_in_set(doc, &ResourceSet::default())
+    }
+
+    fn check_in_set(&self, doc: &Value, set: &ResourceSet<'_>) -> Vec<Violation> {
+        let Some(pod) = workload::pod_spec(doc) else { return vec![] };
+        match pod.spec.get("automountServiceAccountToken").and_then(Value::as_bool) {
+            Some(false) => return vec![],
+            Some(true) if is_bound(set, doc, &pod) => return vec![],
+            Some(true) => {
+                return vec![Violation::new(
+                    "Pod mounts a ServiceAccount token but no RBAC binding grants it any access.",
+                    format!("{}.automountServiceAccountToken", pod.path),
+                )]
+            }
+            None => {}
+        }
+
+        // Without a pod-level setting the ServiceAccount's own one applies.
+        let account = service_account_name(&pod);
+        let namespace = metadata_namespace(doc);
+        let account_disables = set
+            .find(&["ServiceAccount"], account, namespace)
+            .is_some_and(|sa| sa.get("automountServiceAccountToken").and_then(Value::as_bool) == Some(false));
+        if account_disables || is_bound(set, doc, &pod) {
+            return vec![];
+        }
+
+        vec![Violation::new(
+            format!(
+                "Pod does not set automountServiceAccountToken to false and no RBAC binding grants ServiceAccount '{}' any access.",
+                account
+            ),
+            format!("{}.automountServiceAccountToken", pod.path),
+        )]
+    }
+}
+
+/// Whether a binding in `set` has the pod's ServiceAccount as a subject.
+fn is_bound(set: &ResourceSet<'_>, doc: &Value, pod: &workload::PodSpec<'_>) -> bool {
+    let account = service_account_name(pod);
+    let namespace = metadata_namespace(doc);
+
+    set.of_kind(&["RoleBinding", "ClusterRoleBinding"]).any(|binding| {
+        let mut subjects = binding.get("subjects").and_then(Value::as_sequence).into_iter().flatten();
+        subjects.any(|subject| {
+            // A subject without a namespace is in the binding's namespace.
+            let subject_namespace = subject.get("namespace").and_then(Value::as_str).or_else(|| metadata_namespace(binding));
+            subject.get("kind").and_then(Value::as_str) == Some("ServiceAccount")
+                && subject.get("name").and_then(Value::as_str) == Some(account)
+                && same_namespace(subject_namespace, namespace)
+        })
+    })
+}
+
+fn service_account_name<'a>(pod: &workload::PodSpec<'a>) -> &'a str {
+    pod.spec
+        .get("serviceAccountName")
+        .or_else(|| pod.spec.get("serviceAccount"))
+        .and_then(Value::as_str)
+        .unwrap_or("default")
+}
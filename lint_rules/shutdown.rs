@@ -0,0 +1,96 @@
+use serde_yaml::Value;
+
+use super::{traffic, workload, LintRule, ResourceSet, Violation};
+
+/// Kubernetes default for `terminationGracePeriodSeconds`.
+const DEFAULT_GRACE_PERIOD: i64 = 30;
+
+/// Flags workloads that cannot shut down cleanly: a grace period shorter
+/// than the readiness probe needs to mark the pod unready or than the
+/// preStop hook takes, and no preStop hook on pods behind a Service, which
+/// keep receiving traffic while endpoints are being updated.
+pub struct GracefulShutdownRule;
+
+impl LintRule for GracefulShutdownRule {
+    fn id(&self) -> &'static str {
+        "graceful-shutdown"
+    }
+
+    fn targets(&self, doc: &Value) -> bool {
+        workload::pod_spec(doc).is_some_and(|pod| !pod.is_batch())
+    }
+
+    fn check(&self, doc: &Value) -> Vec<Violation> {
+        self.check_in_set(doc, &ResourceSet::default())
+    }
+
+    fn check_in_set(&self, doc: &Value, set: &ResourceSet<'_>) -> Vec<Violation> {
+        let Some(pod) = workload::pod_spec(doc).filter(|pod| !pod.is_batch()) else { return vec![] };
+        let grace_period = pod.spec.get("terminationGracePeriodSeconds").and_then(Value::as_i64).unwrap_or(DEFAULT_GRACE_PERIOD);
+        let grace_path = format!("{}.terminationGracePeriodSeconds", pod.path);
+        let containers: Vec<_> = pod.all_containers().into_iter().filter(|c| c.is_long_running()).collect();
+
+        let mut violations = vec![];
+        for container in &containers {
+            if let Some(readiness) = container.value.get("readinessProbe") {
+                // Defaults: periodSeconds 10, failureThreshold 3.
+                let period = readiness.get("periodSeconds").and_then(Value::as_i64).unwrap_or(10);
+                let threshold = readiness.get("failureThreshold").and_then(Value::as_i64).unwrap_or(3);
+                if grace_period < period * threshold {
+                    violations.push(Violation::new(
+                        format!(
+                            "{} readinessProbe takes {}s to report unready, longer than terminationGracePeriodSeconds ({}).",
+                            container.label(),
+                            period * threshold,
+                            grace_period
+                        ),
+                        grace_path.clone(),
+                    ));
+                }
+            }
+
+            if let Some(sleep) = pre_stop_sleep(container.value) {
+                if sleep >= grace_period {
+                    violations.push(Violation::new(
+                        format!(
+                            "{} preStop hook sleeps {}s, leaving no time to shut down within terminationGracePeriodSeconds ({}).",
+                            container.label(),
+                            sleep,
+                            grace_period
+                        ),
+                        grace_path.clone(),
+                    ));
+                }
+            }
+        }
+
+        let has_pre_stop = containers.iter().any(|c| c.value.get("lifecycle").and_then(|l| l.get("preStop")).is_some());
+        let behind_service = set.of_kind(&["Service"]).any(|service| traffic::selects_pods(service, doc));
+        if behind_service && !has_pre_stop {
+            if let Some(container) = containers.first() {
+                violations.push(Violation::new(
+                    "Pods receive Service traffic but have no preStop hook to drain connections before shutdown.",
+                    format!("{}.lifecycle.preStop", container.path),
+                ));
+            }
+        }
+        violations
+    }
+}
+
+/// Seconds a `sleep` preStop action or `sleep N` exec hook waits.
+fn pre_stop_sleep(container: &Value) -> Option<i64> {
+    let pre_stop = container.get("lifecycle")?.get("preStop")?;
+    if let Some(seconds) = pre_stop.get("sleep").and_then(|s| s.get("seconds")).and_then(Value::as_i64) {
+        return Some(seconds);
+    }
+    let command = pre_stop.get("exec")?.get("command")?.as_sequence()?;
+    let command: Vec<&str> = command.iter().filter_map(Value::as_str).collect();
+    // `["sleep", "15"]` or `["sh", "-c", "sleep 15"]`.
+    command
+        .iter()
+        .flat_map(|part| part.split_whitespace())
+        .skip_while(|word| !word.ends_with("sleep"))
+        .nth(1)
+        .and_then(|seconds| seconds.parse().ok())
+}
@@ -0,0 +1,97 @@
+use serde_yaml::Value;
+
+use super::{workload, LintRule, Violation};
+use crate::config::AnnotationFormat;
+use crate::utils;
+use std::collections::BTreeMap;
+
+/// For production clusters: workloads must carry the configured SLO and
+/// alerting annotations, each in its configured format.
+pub struct SloAnnotationsRule {
+    annotations: BTreeMap<String, AnnotationFormat>,
+    production: bool,
+}
+
+impl SloAnnotationsRule {
+    pub fn new(annotations: BTreeMap<String, AnnotationFormat>, production: bool) -> Self {
+        SloAnnotationsRule { annotations, production }
+    }
+}
+
+impl LintRule for SloAnnotationsRule {
+    fn id(&self) -> &'static str {
+        "slo-annotations"
+    }
+
+    fn targets(&self, doc: &Value) -> bool {
+        self.production && !self.annotations.is_empty() && workload::pod_spec(doc).is_some()
+    }
+
+    fn check(&self, doc: &Value) -> Vec<Violation> {
+        if !self.production {
+            return vec![];
+        }
+        // Bare Pods are usually debugging leftovers, not paged services.
+        let Some(pod) = workload::pod_spec(doc) else { return vec![] };
+        if pod.kind == "Pod" {
+            return vec![];
+        }
+        let annotations = doc.get("metadata").and_then(|m| m.get("annotations"));
+
+        let mut violations = vec![];
+        for (name, format) in &self.annotations {
+            let field = format!("metadata.annotations.{}", name);
+            match annotations.and_then(|a| a.get(name.as_str())).and_then(Value::as_str) {
+                None => violations.push(Violation::new(format!("Workload is missing the {} annotation.", name), field)),
+                Some(value) if !format.accepts(value) => violations.push(Violation::new(
+                    format!("Annotation {} value '{}' {}.", name, value, format.problem()),
+                    field,
+                )),
+                Some(_) => {}
+            }
+        }
+        violations
+    }
+}
+
+impl AnnotationFormat {
+    fn accepts(&self, value: &str) -> bool {
+        match self {
+            AnnotationFormat::Any => !value.trim().is_empty(),
+            AnnotationFormat::Percentage => value
+                .strip_suffix('%')
+                .unwrap_or(value)
+                .parse::<f64>()
+                .is_ok_and(|percent| percent > 0.0 && percent <= 100.0),
+            AnnotationFormat::Duration => is_duration(value),
+            AnnotationFormat::Pattern(pattern) => utils::glob_match(pattern, value),
+        }
+    }
+
+    fn problem(&self) -> String {
+        match self {
+            AnnotationFormat::Any => "is empty".to_string(),
+            AnnotationFormat::Percentage => "is not a percentage between 0 and 100".to_string(),
+            AnnotationFormat::Duration => "is not a duration such as 30d or 5m".to_string(),
+            AnnotationFormat::Pattern(pattern) => format!("does not match '{}'", pattern),
+        }
+    }
+}
+
+/// A Prometheus-style duration: one or more `<number><unit>` pairs, e.g.
+/// `30d` or `1h30m`.
+fn is_duration(value: &str) -> bool {
+    let mut rest = value;
+    while !rest.is_empty() {
+        let digits = rest.len() - rest.trim_start_matches(|c: char| c.is_ascii_digit()).len();
+        if digits == 0 {
+            return false;
+        }
+        rest = &rest[digits..];
+        let Some(unit) = ["ms", "s", "m", "h", "d", "w", "y"].into_iter().find(|unit| rest.starts_with(unit)) else {
+            return false;
+        };
+        rest = &rest[unit.len()..];
+    }
+    !value.is_empty()
+}
@@ -0,0 +1,67 @@
+use serde_yaml::Value;
+
+use super::{is_kind, metadata_namespace, LintRule, ResourceSet, Violation};
+
+/// Flags StatefulSets whose `serviceName` does not name a headless Service
+/// in the input, which leaves pods without stable DNS names, and warns about
+/// ones that leave `podManagementPolicy` to its OrderedReady default. A
+/// Service missing from the input is a warning when config says Services
+/// may be created outside the repo.
+pub struct StatefulSetRule {
+    missing_as_warning: bool,
+}
+
+impl StatefulSetRule {
+    pub fn new(missing_as_warning: bool) -> Self {
+        StatefulSetRule { missing_as_warning }
+    }
+}
+
+impl LintRule for StatefulSetRule {
+    fn id(&self) -> &'static str {
+        "statefulset"
+    }
+
+    fn targets(&self, doc: &Value) -> bool {
+        is_kind(doc, &["StatefulSet"])
+    }
+
+    fn check(&self, doc: &Value) -> Vec<Violation> {
+        self.check_in_set(doc, &ResourceSet::default())
+    }
+
+    fn check_in_set(&self, doc: &Value, set: &ResourceSet<'_>) -> Vec<Violation> {
+        if doc.get("kind").and_then(Value::as_str) != Some("StatefulSet") {
+            return vec![];
+        }
+        let spec = doc.get("spec");
+
+        let mut violations = vec![];
+        if let Some(service_name) = spec.and_then(|s| s.get("serviceName")).and_then(Value::as_str) {
+            match set.find(&["Service"], service_name, metadata_namespace(doc)) {
+                None => {
+                    let message = format!("StatefulSet serviceName '{}' does not match any Service.", service_name);
+                    violations.push(if self.missing_as_warning {
+                        Violation::warning(message, "spec.serviceName")
+                    } else {
+                        Violation::new(message, "spec.serviceName")
+                    });
+                }
+                Some(service) if service.get("spec").and_then(|s| s.get("clusterIP")).and_then(Value::as_str) != Some("None") => {
+                    violations.push(Violation::new(
+                        format!("StatefulSet serviceName '{}' is not a headless Service (clusterIP: None).", service_name),
+                        "spec.serviceName",
+                    ))
+                }
+                Some(_) => {}
+            }
+        }
+        if spec.and_then(|s| s.get("podManagementPolicy")).is_none() {
+            violations.push(Violation::warning(
+                "StatefulSet does not set podManagementPolicy; pods start and stop one at a time (OrderedReady). Set Parallel if they do not depend on each other.",
+                "spec.podManagementPolicy",
+            ));
+        }
+        violations
+    }
+}
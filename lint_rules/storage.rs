@@ -0,0 +1,125 @@
+use serde_yaml::Value;
+
+use super::{is_kind, workload, LintRule, Violation};
+
+/// Guards data against deletion: StatefulSets with volumeClaimTemplates
+/// must configure `persistentVolumeClaimRetentionPolicy` (on Kubernetes
+/// 1.27+, where it is enabled by default), and volumes of storage classes
+/// marked precious must not be deleted with their claims.
+pub struct StorageRetentionRule {
+    precious_storage_classes: Vec<String>,
+    /// `(major, minor)`; the policy is required when unknown.
+    version: Option<(u32, u32)>,
+}
+
+impl StorageRetentionRule {
+    pub fn new(precious_storage_classes: Vec<String>, version: Option<(u32, u32)>) -> Self {
+        StorageRetentionRule { precious_storage_classes, version }
+    }
+
+    fn is_precious(&self, storage_class: Option<&str>) -> bool {
+        storage_class.is_some_and(|class| self.precious_storage_classes.iter().any(|c| c == class))
+    }
+}
+
+impl LintRule for StorageRetentionRule {
+    fn id(&self) -> &'static str {
+        "storage-retention"
+    }
+
+    fn targets(&self, doc: &Value) -> bool {
+        is_kind(doc, &["StatefulSet", "PersistentVolume"])
+    }
+
+    fn check(&self, doc: &Value) -> Vec<Violation> {
+        let spec = doc.get("spec");
+        match doc.get("kind").and_then(Value::as_str) {
+            Some("StatefulSet") => {
+                let Some(templates) = spec.and_then(|s| s.get("volumeClaimTemplates")).and_then(Value::as_sequence) else {
+                    return vec![];
+                };
+                if templates.is_empty() {
+                    return vec![];
+                }
+
+                let Some(policy) = spec.and_then(|s| s.get("persistentVolumeClaimRetentionPolicy")) else {
+                    if self.version.is_some_and(|version| version < (1, 27)) {
+                        return vec![];
+                    }
+                    return vec![Violation::new(
+                        "StatefulSet has volumeClaimTemplates but no persistentVolumeClaimRetentionPolicy.",
+                        "spec.persistentVolumeClaimRetentionPolicy",
+                    )];
+                };
+
+                let precious = templates.iter().any(|template| {
+                    self.is_precious(template.get("spec").and_then(|s| s.get("storageClassName")).and_then(Value::as_str))
+                });
+                if precious && policy.get("whenDeleted").and_then(Value::as_str) == Some("Delete") {
+                    return vec![Violation::new(
+                        "StatefulSet deletes PVCs of a precious storage class when it is deleted.",
+                        "spec.persistentVolumeClaimRetentionPolicy.whenDeleted",
+                    )];
+                }
+                vec![]
+            }
+            Some("PersistentVolume") => {
+                let storage_class = spec.and_then(|s| s.get("storageClassName")).and_then(Value::as_str);
+                let reclaim_policy = spec.and_then(|s| s.get("persistentVolumeReclaimPolicy")).and_then(Value::as_str);
+                if self.is_precious(storage_class) && reclaim_policy == Some("Delete") {
+                    return vec![Violation::new(
+                        format!(
+                            "PersistentVolume of precious storage class '{}' uses persistentVolumeReclaimPolicy: Delete.",
+                            storage_class.unwrap_or_default()
+                        ),
+                        "spec.persistentVolumeReclaimPolicy",
+                    )];
+                }
+                vec![]
+            }
+            _ => vec![],
+        }
+    }
+}
+
+/// Flags `emptyDir` volumes without a `sizeLimit`. Disk-backed ones can
+/// fill the node's disk and get pods evicted (a warning); memory-backed
+/// ones consume node memory that no request accounts for (an error).
+pub struct EmptyDirSizeLimitRule;
+
+impl LintRule for EmptyDirSizeLimitRule {
+    fn id(&self) -> &'static str {
+        "emptydir-size-limit"
+    }
+
+    fn targets(&self, doc: &Value) -> bool {
+        workload::pod_spec(doc).is_some()
+    }
+
+    fn check(&self, doc: &Value) -> Vec<Violation> {
+        let Some(pod) = workload::pod_spec(doc) else { return vec![] };
+        let Some(volumes) = pod.spec.get("volumes").and_then(Value::as_sequence) else { return vec![] };
+
+        let mut violations = vec![];
+        for (i, volume) in volumes.iter().enumerate() {
+            let Some(empty_dir) = volume.get("emptyDir") else { continue };
+            if empty_dir.get("sizeLimit").is_some() {
+                continue;
+            }
+            let name = volume.get("name").and_then(Value::as_str).unwrap_or("unnamed");
+            let field = format!("{}.volumes[{}].emptyDir.sizeLimit", pod.path, i);
+            if empty_dir.get("medium").and_then(Value::as_str) == Some("Memory") {
+                violations.push(Violation::new(
+                    format!("Memory-backed emptyDir '{}' has no sizeLimit; files written to it consume memory invisibly.", name),
+                    field,
+                ));
+            } else {
+                violations.push(Violation::warning(
+                    format!("emptyDir '{}' has no sizeLimit and can fill the node's disk.", name),
+                    field,
+                ));
+            }
+        }
+        violations
+    }
+}
@@ -0,0 +1,209 @@
+use serde_yaml::Value;
+
+use super::{availability, is_kind, metadata_namespace, same_namespace, selectors, workload, LintRule, ResourceSet, Violation};
+
+const TOPOLOGY_MODE: &str = "service.kubernetes.io/topology-mode";
+const TOPOLOGY_HINTS: &str = "service.kubernetes.io/topology-aware-hints";
+/// Kinds whose pods a Service can select.
+const POD_KINDS: &[&str] = &["Pod", "Deployment", "StatefulSet", "DaemonSet", "ReplicaSet", "Rollout", "Job"];
+
+/// For multi-zone clusters: Services in front of multi-replica workloads
+/// should keep traffic in-zone with the routing hint the cluster's
+/// Kubernetes version supports, to avoid cross-zone transfer costs.
+pub struct TopologyRoutingRule {
+    multi_zone: bool,
+    /// `(major, minor)`; the newest mechanism is suggested when unknown.
+    version: Option<(u32, u32)>,
+}
+
+impl TopologyRoutingRule {
+    pub fn new(multi_zone: bool, version: Option<(u32, u32)>) -> Self {
+        TopologyRoutingRule { multi_zone, version }
+    }
+
+    /// The field to set and the hint to recommend, or `None` before
+    /// Kubernetes 1.23.
+    fn suggestion(&self) -> Option<(&'static str, &'static str)> {
+        match self.version {
+            Some(version) if version < (1, 23) => None,
+            Some(version) if version < (1, 27) => {
+                Some(("metadata.annotations", "annotation service.kubernetes.io/topology-aware-hints: auto"))
+            }
+            Some(version) if version < (1, 31) => {
+                Some(("metadata.annotations", "annotation service.kubernetes.io/topology-mode: Auto"))
+            }
+            _ => Some(("spec.trafficDistribution", "spec.trafficDistribution: PreferClose")),
+        }
+    }
+}
+
+impl LintRule for TopologyRoutingRule {
+    fn id(&self) -> &'static str {
+        "topology-routing"
+    }
+
+    fn targets(&self, doc: &Value) -> bool {
+        self.multi_zone && is_kind(doc, &["Service"])
+    }
+
+    fn check(&self, doc: &Value) -> Vec<Violation> {
+        self.check_in_set(doc, &ResourceSet::default())
+    }
+
+    fn check_in_set(&self, doc: &Value, set: &ResourceSet<'_>) -> Vec<Violation> {
+        if !self.multi_zone || doc.get("kind").and_then(Value::as_str) != Some("Service") {
+            return vec![];
+        }
+        let spec = doc.get("spec");
+        if spec.and_then(|s| s.get("type")).and_then(Value::as_str) == Some("ExternalName") {
+            return vec![];
+        }
+
+        let traffic_distribution = spec.and_then(|s| s.get("trafficDistribution"));
+        if traffic_distribution.is_some() && self.version.is_some_and(|version| version < (1, 30)) {
+            return vec![Violation::new(
+                "spec.trafficDistribution requires Kubernetes 1.30 or later and is ignored by this cluster.",
+                "spec.trafficDistribution",
+            )];
+        }
+
+        let annotations = doc.get("metadata").and_then(|m| m.get("annotations"));
+        let has_hint = traffic_distribution.is_some()
+            || [TOPOLOGY_MODE, TOPOLOGY_HINTS].iter().any(|key| annotations.and_then(|a| a.get(*key)).is_some());
+        let Some((field, suggestion)) = self.suggestion() else { return vec![] };
+        if has_hint || !backs_multi_replica_workload(doc, set) {
+            return vec![];
+        }
+
+        vec![Violation::new(
+            format!("Service fronts a multi-replica workload in a multi-zone cluster; set {} to keep traffic in-zone.", suggestion),
+            field,
+        )]
+    }
+}
+
+/// Services' target ports must exist on the containers their selector
+/// picks. Named ports must be declared; numeric ones are only checked
+/// when the containers declare ports at all, as undeclared ports still
+/// receive traffic.
+pub struct ServiceTargetPortRule;
+
+impl LintRule for ServiceTargetPortRule {
+    fn id(&self) -> &'static str {
+        "service-target-port"
+    }
+
+    fn targets(&self, doc: &Value) -> bool {
+        is_kind(doc, &["Service"])
+    }
+
+    fn check(&self, doc: &Value) -> Vec<Violation> {
+        self.check_in_set(doc, &ResourceSet::default())
+    }
+
+    fn check_in_set(&self, doc: &Value, set: &ResourceSet<'_>) -> Vec<Violation> {
+        if doc.get("kind").and_then(Value::as_str) != Some("Service") {
+            return vec![];
+        }
+        let workloads: Vec<&Value> = set.of_kind(POD_KINDS).filter(|workload| selects_pods(doc, workload)).collect();
+        if workloads.is_empty() {
+            return vec![];
+        }
+        let declared: Vec<&Value> = workloads
+            .iter()
+            .filter_map(|workload| workload::pod_spec(workload))
+            .flat_map(|pod| pod.all_containers())
+            .filter(|container| container.is_long_running())
+            .flat_map(|container| container.value.get("ports").and_then(Value::as_sequence).into_iter().flatten())
+            .collect();
+
+        let mut violations = vec![];
+        let ports = doc.get("spec").and_then(|s| s.get("ports")).and_then(Value::as_sequence).into_iter().flatten();
+        for (i, port) in ports.enumerate() {
+            let Some(target) = port.get("targetPort").or_else(|| port.get("port")) else { continue };
+            let (found, target) = match target {
+                Value::String(name) => (declared.iter().any(|p| p.get("name").and_then(Value::as_str) == Some(name)), name.clone()),
+                Value::Number(number) => (
+                    declared.is_empty() || declared.iter().any(|p| p.get("containerPort") == Some(target)),
+                    number.to_string(),
+                ),
+                _ => continue,
+            };
+            if !found {
+                violations.push(Violation::new(
+                    format!("Service targets port {}, which no container selected by the Service declares.", target),
+                    format!("spec.ports[{}].targetPort", i),
+                ));
+            }
+        }
+        violations
+    }
+}
+
+/// Flags a `containerPort` (per protocol) or port name declared more than
+/// once across a pod's long-running containers. They share the pod's
+/// network, so duplicate ports conflict and duplicate names make Service
+/// `targetPort` lookups ambiguous.
+pub struct ContainerPortsRule;
+
+impl LintRule for ContainerPortsRule {
+    fn id(&self) -> &'static str {
+        "container-ports"
+    }
+
+    fn targets(&self, doc: &Value) -> bool {
+        workload::pod_spec(doc).is_some()
+    }
+
+    fn check(&self, doc: &Value) -> Vec<Violation> {
+        let Some(pod) = workload::pod_spec(doc) else { return vec![] };
+
+        let mut numbers: Vec<(i64, &str)> = vec![];
+        let mut names: Vec<&str> = vec![];
+        let mut violations = vec![];
+        for container in pod.all_containers().into_iter().filter(|c| c.is_long_running()) {
+            let ports = container.value.get("ports").and_then(Value::as_sequence).into_iter().flatten();
+            for (i, port) in ports.enumerate() {
+                let path = format!("{}.ports[{}]", container.path, i);
+                if let Some(number) = port.get("containerPort").and_then(Value::as_i64) {
+                    let protocol = port.get("protocol").and_then(Value::as_str).unwrap_or("TCP");
+                    if numbers.contains(&(number, protocol)) {
+                        violations.push(Violation::new(
+                            format!("containerPort {}/{} is declared more than once in the pod.", number, protocol),
+                            format!("{}.containerPort", path),
+                        ));
+                    }
+                    numbers.push((number, protocol));
+                }
+                if let Some(name) = port.get("name").and_then(Value::as_str) {
+                    if names.contains(&name) {
+                        violations.push(Violation::new(
+                            format!("Port name '{}' is declared more than once in the pod.", name),
+                            format!("{}.name", path),
+                        ));
+                    }
+                    names.push(name);
+                }
+            }
+        }
+        violations
+    }
+}
+
+/// Whether the Service's selector picks the pods of a workload in `set`
+/// that runs more than one replica.
+fn backs_multi_replica_workload(service: &Value, set: &ResourceSet<'_>) -> bool {
+    set.of_kind(&["Deployment", "StatefulSet", "Rollout"])
+        .any(|workload| selects_pods(service, workload) && availability::replicas(set, workload) > 1)
+}
+
+/// Whether `service`'s selector picks the pods of `workload`. Services
+/// without a selector pick nothing.
+pub fn selects_pods(service: &Value, workload: &Value) -> bool {
+    let Some(selector) = service.get("spec").and_then(|s| s.get("selector")).and_then(Value::as_mapping) else { return false };
+    let labels = selectors::pod_labels(workload);
+
+    !selector.is_empty()
+        && same_namespace(metadata_namespace(workload), metadata_namespace(service))
+        && selector.iter().all(|(key, value)| labels.and_then(|l| l.get(key)) == Some(value))
+}
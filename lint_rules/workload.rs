@@ -0,0 +1,118 @@
+use serde_yaml::Value;
+
+/// The pod spec of a workload, wherever its kind keeps it.
+pub struct PodSpec<'a> {
+    pub kind: &'a str,
+    pub spec: &'a Value,
+    /// Dotted path of `spec` within the document.
+    pub path: &'static str,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContainerKind {
+    Regular,
+    Init,
+    /// Init container with `restartPolicy: Always`, running alongside the
+    /// regular containers for the pod's lifetime.
+    Sidecar,
+    Ephemeral,
+}
+
+pub struct Container<'a> {
+    pub kind: ContainerKind,
+    pub value: &'a Value,
+    /// Dotted path of the container within the document.
+    pub path: String,
+}
+
+impl Container<'_> {
+    /// Capitalized name for messages, e.g. "Init container".
+    pub fn label(&self) -> &'static str {
+        match self.kind {
+            ContainerKind::Regular => "Container",
+            ContainerKind::Init => "Init container",
+            ContainerKind::Sidecar => "Sidecar container",
+            ContainerKind::Ephemeral => "Ephemeral container",
+        }
+    }
+
+    /// Runs for the pod's lifetime, so probes and the like apply.
+    pub fn is_long_running(&self) -> bool {
+        matches!(self.kind, ContainerKind::Regular | ContainerKind::Sidecar)
+    }
+}
+
+/// Finds the pod spec of a Pod, a CronJob, or any kind with a pod template
+/// at `spec.template` (Deployment, StatefulSet, DaemonSet, ReplicaSet, Job,
+/// and CRDs following the same convention).
+pub fn pod_spec(doc: &Value) -> Option<PodSpec<'_>> {
+    let kind = doc.get("kind").and_then(Value::as_str)?;
+    let path = match kind {
+        "Pod" => "spec",
+        "CronJob" => "spec.jobTemplate.spec.template.spec",
+        _ => "spec.template.spec",
+    };
+    let spec = path.split('.').try_fold(doc, |value, key| value.get(key))?;
+    Some(PodSpec { kind, spec, path })
+}
+
+impl<'a> PodSpec<'a> {
+    /// Jobs and CronJobs run to completion; long-running concerns like
+    /// probes do not apply to them.
+    pub fn is_batch(&self) -> bool {
+        matches!(self.kind, "Job" | "CronJob")
+    }
+
+    /// Dotted path of the pod's metadata, which sits next to its spec; a
+    /// bare Pod is its own template.
+    pub fn metadata_path(&self) -> String {
+        match self.path.strip_suffix(".spec") {
+            Some(path) => format!("{}.metadata", path),
+            None => "metadata".to_string(),
+        }
+    }
+
+    pub fn containers(&self) -> Vec<Container<'a>> {
+        self.list("containers", |_| ContainerKind::Regular)
+    }
+
+    pub fn init_containers(&self) -> Vec<Container<'a>> {
+        self.list("initContainers", |container| {
+            match container.get("restartPolicy").and_then(Value::as_str) {
+                Some("Always") => ContainerKind::Sidecar,
+                _ => ContainerKind::Init,
+            }
+        })
+    }
+
+    pub fn ephemeral_containers(&self) -> Vec<Container<'a>> {
+        self.list("ephemeralContainers", |_| ContainerKind::Ephemeral)
+    }
+
+    /// Regular, init and ephemeral containers, in that order.
+    pub fn all_containers(&self) -> Vec<Container<'a>> {
+        let mut containers = self.containers();
+        containers.extend(self.init_containers());
+        containers.extend(self.ephemeral_containers());
+        containers
+    }
+
+    fn list(&self, field: &str, kind: impl Fn(&Value) -> ContainerKind) -> Vec<Container<'a>> {
+        let Some(containers) = self.spec.get(field).and_then(Value::as_sequence) else { return vec![] };
+        containers
+            .iter()
+            .enumerate()
+            .map(|(i, value)| Container { kind: kind(value), value, path: format!("{}.{}[{}]", self.path, field, i) })
+            .collect()
+    }
+
+    /// A `securityContext` setting for `container`, falling back to the
+    /// pod-level value the container inherits.
+    pub fn effective_security_setting(&self, container: &Container<'a>, key: &str) -> Option<&'a Value> {
+        container
+            .value
+            .get("securityContext")
+            .and_then(|context| context.get(key))
+            .or_else(|| self.spec.get("securityContext").and_then(|context| context.get(key)))
+    }
+}
@@ -0,0 +1,485 @@
+use serde_yaml::Value;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::str::FromStr;
+
+use memmap2::Mmap;
+
+use crate::config::Config;
+use crate::lint_rules::{metadata_name, metadata_namespace, LintRule, Origin, ResourceSet, Severity};
+use crate::reporter::{Reporter, ResourceRef};
+use crate::source::{Location, SourceMap};
+use crate::utils;
+use crate::{Error, Result};
+
+#[derive(Debug, Clone)]
+pub struct Finding {
+    pub rule: &'static str,
+    pub message: String,
+    /// Dotted path of the offending field within the document.
+    pub field: String,
+    /// Position of `field` (or its nearest existing ancestor) in the file.
+    pub location: Option<Location>,
+    /// Remediation docs for the rule, from the `rule-docs` config.
+    pub docs: Option<String>,
+    pub severity: Severity,
+}
+
+/// Lint outcome for a single YAML document.
+#[derive(Debug, Clone)]
+pub struct ResourceReport {
+    pub index: usize,
+    pub kind: String,
+    pub name: String,
+    /// Line on which the document starts, when the source text is known.
+    pub line: Option<usize>,
+    /// Ids of the active rules that check this kind of resource.
+    pub rules: Vec<&'static str>,
+    pub issues: Vec<Finding>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SkipReason {
+    /// Unrendered Helm/Go template source.
+    Templated,
+    /// Every rule is disabled for the resource by config.
+    IgnoredByConfig,
+    /// No rule selected with `--rules` applies to the resource.
+    NotSelected,
+    /// The document has no `kind`, so no rule can apply.
+    UnsupportedKind,
+    ParseError(String),
+    Empty,
+    /// The file is bigger than the configured `max_file_size`.
+    TooLarge { size: u64, limit: u64 },
+    /// Not a Kubernetes resource, e.g. a CI workflow or docker-compose file,
+    /// or a file without `kind:` and `apiVersion:` markers.
+    NotKubernetes,
+}
+
+impl fmt::Display for SkipReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SkipReason::Templated => write!(f, "templated"),
+            SkipReason::IgnoredByConfig => write!(f, "ignored by config"),
+            SkipReason::NotSelected => write!(f, "no selected rule applies"),
+            SkipReason::UnsupportedKind => write!(f, "unsupported kind"),
+            SkipReason::ParseError(_) => write!(f, "parse error"),
+            SkipReason::Empty => write!(f, "empty document"),
+            SkipReason::TooLarge { .. } => write!(f, "too large"),
+            SkipReason::NotKubernetes => write!(f, "not Kubernetes"),
+        }
+    }
+}
+
+/// A file (`document: None`) or document that was not linted.
+#[derive(Debug, Clone)]
+pub struct Skipped {
+    pub path: String,
+    pub document: Option<usize>,
+    pub reason: SkipReason,
+}
+
+/// Narrows linting to matching documents, e.g. one workload in a shared
+/// multi-document manifest. Parsed from `key=value` pairs joined by commas,
+/// with keys `kind`, `name` and `namespace`; every given key must match.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Target {
+    pub kind: Option<String>,
+    pub name: Option<String>,
+    pub namespace: Option<String>,
+}
+
+impl FromStr for Target {
+    type Err = String;
+
+    fn from_str(spec: &str) -> std::result::Result<Self, Self::Err> {
+        let mut target = Target::default();
+        for pair in spec.split(',') {
+            let Some((key, value)) = pair.split_once('=') else {
+                return Err(format!("expected key=value, got '{}'", pair));
+            };
+            let field = match key.trim() {
+                "kind" => &mut target.kind,
+                "name" => &mut target.name,
+                "namespace" => &mut target.namespace,
+                other => return Err(format!("unknown key '{}'; expected kind, name or namespace", other)),
+            };
+            *field = Some(value.trim().to_string());
+        }
+        Ok(target)
+    }
+}
+
+impl Target {
+    /// Kinds match case-insensitively; documents without a namespace are
+    /// in `default`.
+    pub fn matches(&self, doc: &Value) -> bool {
+        let kind = doc.get("kind").and_then(Value::as_str);
+        self.kind.as_deref().is_none_or(|want| kind.is_some_and(|kind| kind.eq_ignore_ascii_case(want)))
+            && self.name.as_deref().is_none_or(|want| metadata_name(doc) == Some(want))
+            && self.namespace.as_deref().is_none_or(|want| metadata_namespace(doc).unwrap_or("default") == want)
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct LintReport {
+    pub path: String,
+    pub resources: Vec<ResourceReport>,
+    pub skipped: Vec<Skipped>,
+}
+
+impl LintReport {
+    pub fn total_issues(&self) -> usize {
+        self.resources.iter().map(|r| r.issues.len()).sum()
+    }
+}
+
+pub struct Linter {
+    rules: Vec<Box<dyn LintRule>>,
+    config: Config,
+    max_file_size: Option<u64>,
+    prescan: bool,
+    include_non_kubernetes: bool,
+    targets: Vec<Target>,
+    /// Ids of the rules to run; all when empty.
+    selected: Vec<String>,
+}
+
+impl Linter {
+    pub fn new(rules: Vec<Box<dyn LintRule>>, config: Config) -> Self {
+        Linter { rules, config, max_file_size: None, prescan: false, include_non_kubernetes: false, targets: vec![], selected: vec![] }
+    }
+
+    /// Files larger than `limit` bytes are skipped without being read, so an
+    /// accidental data dump cannot balloon memory.
+    pub fn with_max_file_size(mut self, limit: Option<u64>) -> Self {
+        self.max_file_size = limit;
+        self
+    }
+
+    /// Memory-maps each file and skips it without parsing unless it has
+    /// both `kind` and `apiVersion` keys, in YAML or JSON form, which makes
+    /// repos full of CI configs and other YAML cheap to lint. Files that
+    /// pass are parsed straight from the map, without copying them.
+    pub fn with_prescan(mut self, prescan: bool) -> Self {
+        self.prescan = prescan;
+        self
+    }
+
+    /// Lints documents that do not look like Kubernetes resources instead
+    /// of skipping them.
+    pub fn with_include_non_kubernetes(mut self, include: bool) -> Self {
+        self.include_non_kubernetes = include;
+        self
+    }
+
+    /// Only lints documents matching one of `targets`, or all documents
+    /// when empty. Other documents are left out of the report but still
+    /// visible to cross-resource rules.
+    pub fn with_targets(mut self, targets: Vec<Target>) -> Self {
+        self.targets = targets;
+        self
+    }
+
+    /// Only runs the rules with these ids, or every rule when empty.
+    /// Documents no selected rule applies to are skipped as `NotSelected`
+    /// rather than `IgnoredByConfig`.
+    pub fn with_selected_rules(mut self, ids: Vec<String>) -> Self {
+        self.selected = ids;
+        self
+    }
+
+    /// Ids of the rules this linter runs.
+    pub fn rule_ids(&self) -> impl Iterator<Item = &'static str> + '_ {
+        self.rules.iter().map(|rule| rule.id()).filter(|id| self.is_selected(id))
+    }
+
+    pub fn lint_file(&self, path: &str) -> Result<LintReport> {
+        self.lint_file_with(path, &mut ())
+    }
+
+    /// Like `lint_file`, streaming results to `reporter` as they are produced.
+    pub fn lint_file_with(&self, path: &str, reporter: &mut dyn Reporter) -> Result<LintReport> {
+        let file = self.load(path)?;
+        let set = ResourceSet::with_origins(file.documents());
+        Ok(self.lint_loaded(&file, &set, reporter))
+    }
+
+    /// Lints `paths` as one input set, so rules that correlate resources see
+    /// the documents of every file. Each file gets its own report, or the
+    /// error that kept it from being read.
+    pub fn lint_files_with(&self, paths: &[String], reporter: &mut dyn Reporter) -> Vec<Result<LintReport>> {
+        let files: Vec<_> = paths.iter().map(|path| self.load(path)).collect();
+        let set = ResourceSet::with_origins(files.iter().flatten().flat_map(LoadedFile::documents));
+        let reports: Vec<_> = files
+            .iter()
+            .map(|file| file.as_ref().ok().map(|file| self.lint_loaded(file, &set, reporter)))
+            .collect();
+        files.into_iter().zip(reports).map(|(file, report)| file.map(|_| report.unwrap_or_default())).collect()
+    }
+
+    /// Lints YAML `contents`; `path` is only used to match config overrides.
+    /// Documents that cannot be parsed are recorded as skipped, along with
+    /// any documents after them.
+    pub fn lint_str(&self, path: &str, contents: &str) -> LintReport {
+        self.lint_str_with(path, contents, &mut ())
+    }
+
+    /// Like `lint_str`, streaming results to `reporter` as they are produced.
+    pub fn lint_str_with(&self, path: &str, contents: &str, reporter: &mut dyn Reporter) -> LintReport {
+        let file = LoadedFile::parse(path, Contents::Read(contents.to_string()));
+        let set = ResourceSet::with_origins(file.documents());
+        self.lint_loaded(&file, &set, reporter)
+    }
+
+    /// Lints already-parsed documents. Findings carry no source locations.
+    pub fn lint_documents(&self, path: &str, docs: &[Value]) -> LintReport {
+        let set = ResourceSet::with_origins(docs.iter().enumerate().map(|(index, doc)| (Origin { path, index }, doc)));
+        self.lint(path, docs, None, &set, &mut ())
+    }
+
+    fn load(&self, path: &str) -> Result<LoadedFile> {
+        if let Some(limit) = self.max_file_size {
+            let size = fs::metadata(path).map_err(|source| Error::Io { path: path.to_string(), source })?.len();
+            if size > limit {
+                return Ok(LoadedFile::skipped(path, SkipReason::TooLarge { size, limit }));
+            }
+        }
+        let io_error = |source| Error::Io { path: path.to_string(), source };
+        if self.prescan {
+            let file = fs::File::open(path).map_err(io_error)?;
+            // SAFETY: the map is read-only and lives as long as the loaded
+            // file. A file truncated while mapped can fault, as with any
+            // reader racing a writer.
+            let map = unsafe { Mmap::map(&file) }.map_err(io_error)?;
+            if !has_key(&map, b"kind") || !has_key(&map, b"apiVersion") {
+                return Ok(LoadedFile::skipped(path, SkipReason::NotKubernetes));
+            }
+            std::str::from_utf8(&map).map_err(|err| io_error(io::Error::new(io::ErrorKind::InvalidData, err)))?;
+            return Ok(LoadedFile::parse(path, Contents::Mapped(map)));
+        }
+        let contents = fs::read_to_string(path).map_err(io_error)?;
+        Ok(LoadedFile::parse(path, Contents::Read(contents)))
+    }
+
+    fn lint_loaded(&self, file: &LoadedFile, set: &ResourceSet<'_>, reporter: &mut dyn Reporter) -> LintReport {
+        let path = file.path.as_str();
+        reporter.file_started(path);
+
+        if let Some(reason) = &file.skipped {
+            let skipped = Skipped { path: path.to_string(), document: None, reason: reason.clone() };
+            reporter.skipped(&skipped);
+            return LintReport {
+                path: path.to_string(),
+                skipped: vec![skipped],
+                ..LintReport::default()
+            };
+        }
+
+        let source = SourceMap::new(file.contents.as_str());
+        let mut report = self.lint(path, &file.docs, Some(&source), set, reporter);
+        if let Some(error) = &file.error {
+            let skipped = Skipped {
+                path: path.to_string(),
+                document: Some(file.docs.len()),
+                reason: SkipReason::ParseError(error.clone()),
+            };
+            reporter.skipped(&skipped);
+            report.skipped.push(skipped);
+        }
+        report
+    }
+
+    fn lint(
+        &self,
+        path: &str,
+        docs: &[Value],
+        source: Option<&SourceMap>,
+        set: &ResourceSet<'_>,
+        reporter: &mut dyn Reporter,
+    ) -> LintReport {
+        let mut report = LintReport { path: path.to_string(), ..LintReport::default() };
+
+        for (index, doc) in docs.iter().enumerate() {
+            if !self.targets.is_empty() && !self.targets.iter().any(|target| target.matches(doc)) {
+                continue;
+            }
+            match self.skip_reason(path, doc) {
+                Some(reason) => {
+                    let skipped = Skipped { path: path.to_string(), document: Some(index), reason };
+                    reporter.skipped(&skipped);
+                    report.skipped.push(skipped);
+                }
+                None => {
+                    let resource = self.lint_document(path, index, doc, source, set, reporter);
+                    reporter.resource_finished(path, &resource);
+                    report.resources.push(resource);
+                }
+            }
+        }
+        report
+    }
+
+    fn skip_reason(&self, path: &str, doc: &Value) -> Option<SkipReason> {
+        if doc.is_null() {
+            return Some(SkipReason::Empty);
+        }
+        if !self.include_non_kubernetes && !looks_like_kubernetes(doc) {
+            return Some(SkipReason::NotKubernetes);
+        }
+        let Some(kind) = doc.get("kind").and_then(Value::as_str) else {
+            return Some(SkipReason::UnsupportedKind);
+        };
+        let mut enabled = self.rules.iter().filter(|rule| self.is_rule_enabled(rule.as_ref(), path, kind)).peekable();
+        if enabled.peek().is_none() {
+            return Some(SkipReason::IgnoredByConfig);
+        }
+        if !enabled.any(|rule| self.is_selected(rule.id())) {
+            return Some(SkipReason::NotSelected);
+        }
+        None
+    }
+
+    fn is_selected(&self, id: &str) -> bool {
+        self.selected.is_empty() || self.selected.iter().any(|selected| selected == id)
+    }
+
+    fn is_rule_enabled(&self, rule: &dyn LintRule, path: &str, kind: &str) -> bool {
+        rule.applies_to(path) && self.config.is_rule_enabled(rule.id(), path, kind)
+    }
+
+    fn is_rule_active(&self, rule: &dyn LintRule, path: &str, kind: &str) -> bool {
+        self.is_selected(rule.id()) && self.is_rule_enabled(rule, path, kind)
+    }
+
+    fn lint_document(
+        &self,
+        path: &str,
+        index: usize,
+        doc: &Value,
+        source: Option<&SourceMap>,
+        set: &ResourceSet<'_>,
+        reporter: &mut dyn Reporter,
+    ) -> ResourceReport {
+        let kind = doc
+            .get("kind")
+            .and_then(|v| v.as_str())
+            .unwrap_or("Unknown type");
+
+        let name = doc
+            .get("metadata")
+            .and_then(|metadata| metadata.get("name"))
+            .and_then(|name| name.as_str())
+            .unwrap_or("Unnamed resource");
+
+        let resource = ResourceRef { path, index, kind, name };
+        let mut rules = vec![];
+        let mut issues = vec![];
+        for rule in self.rules.iter().filter(|rule| self.is_rule_active(rule.as_ref(), path, kind)) {
+            if rule.targets(doc) {
+                rules.push(rule.id());
+            }
+            for violation in rule.check_in_set(doc, set) {
+                let finding = Finding {
+                    rule: rule.id(),
+                    location: source.and_then(|s| s.locate(index, &violation.field)),
+                    message: violation.message,
+                    field: violation.field,
+                    docs: self.config.rule_docs.get(rule.id()).cloned(),
+                    severity: violation.severity,
+                };
+                reporter.finding(&resource, &finding);
+                issues.push(finding);
+            }
+        }
+
+        ResourceReport {
+            index,
+            kind: kind.to_string(),
+            name: name.to_string(),
+            line: source.and_then(|s| s.document_start(index)),
+            rules,
+            issues,
+        }
+    }
+}
+
+/// A file read and parsed ahead of linting, so every file's documents can
+/// be in the `ResourceSet` before any of them is checked.
+struct LoadedFile {
+    path: String,
+    contents: Contents,
+    docs: Vec<Value>,
+    /// Parse error that stopped parsing after `docs`.
+    error: Option<String>,
+    /// Why the whole file is skipped, if it is.
+    skipped: Option<SkipReason>,
+}
+
+/// Kubernetes resources are mappings with a `kind` and an `apiVersion` or
+/// `metadata`; anything else found in a YAML file is some other tool's
+/// config.
+fn looks_like_kubernetes(doc: &Value) -> bool {
+    let has = |key: &str| doc.get(key).is_some();
+    let kind = doc.get("kind").and_then(Value::as_str);
+    let api_version = doc.get("apiVersion").and_then(Value::as_str);
+    match (kind, api_version) {
+        (Some(_), Some(_)) => true,
+        (Some(_), None) => has("metadata"),
+        // A resource missing its kind is still recognisable by these.
+        (None, Some(_)) => has("metadata") || has("spec"),
+        (None, None) => false,
+    }
+}
+
+/// Whether `key` appears as a mapping key: followed by `:`, optionally
+/// after a closing quote and spaces, so YAML block and flow style and JSON
+/// (`"kind": ...`) all match.
+fn has_key(haystack: &[u8], key: &[u8]) -> bool {
+    haystack.windows(key.len()).enumerate().any(|(start, window)| {
+        if window != key {
+            return false;
+        }
+        let rest = &haystack[start + key.len()..];
+        let rest = rest.strip_prefix(b"\"").or_else(|| rest.strip_prefix(b"'")).unwrap_or(rest);
+        rest.iter().find(|byte| **byte != b' ' && **byte != b'\t') == Some(&b':')
+    })
+}
+
+/// A file's text, read into memory or, with prescan, mapped from disk and
+/// parsed in place.
+enum Contents {
+    Read(String),
+    /// Checked to be valid UTF-8 when mapped.
+    Mapped(Mmap),
+}
+
+impl Contents {
+    fn as_str(&self) -> &str {
+        match self {
+            Contents::Read(contents) => contents,
+            // SAFETY: `load` only maps files it has checked are UTF-8.
+            Contents::Mapped(map) => unsafe { std::str::from_utf8_unchecked(map) },
+        }
+    }
+}
+
+impl LoadedFile {
+    fn parse(path: &str, contents: Contents) -> Self {
+        let (docs, error) = utils::parse_documents(contents.as_str().as_bytes());
+        if error.is_some() && utils::is_templated(contents.as_str()) {
+            return LoadedFile::skipped(path, SkipReason::Templated);
+        }
+        LoadedFile { path: path.to_string(), contents, docs, error: error.map(|e| e.to_string()), skipped: None }
+    }
+
+    fn skipped(path: &str, reason: SkipReason) -> Self {
+        LoadedFile { path: path.to_string(), contents: Contents::Read(String::new()), docs: vec![], error: None, skipped: Some(reason) }
+    }
+
+    fn documents(&self) -> impl Iterator<Item = (Origin<'_>, &Value)> {
+        self.docs.iter().enumerate().map(|(index, doc)| (Origin { path: &self.path, index }, doc))
+    }
+}
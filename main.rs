@@ -1,9 +1,20 @@
 mod commands;
-mod utils;
-mod lint_rules;
 
 use clap::{Parser, Subcommand};
 
+use std::path::PathBuf;
+use std::process;
+
+use rustykube_core::config::{self, Config};
+use rustykube_core::discovery::{self, Discovery};
+use rustykube_core::lint_rules::{self, Severity};
+use rustykube_core::linter::Target;
+use rustykube_core::utils;
+
+use commands::lint::{FileOptions, Inputs, ReportOptions, EXIT_ERROR};
+use commands::output::{Format, Output};
+use commands::style::Style;
+
 #[derive(Parser)]
 #[command(name = "Rusty Kube")]
 #[command(about = "A Rust-based CLI tool for Kubernetes linting and optimization")]
@@ -12,14 +23,103 @@ struct Cli {
     command: Commands,
 }
 
+// Parsed once at startup, so the size of the Lint variant does not matter.
+#[allow(clippy::large_enum_variant)]
 #[derive(Subcommand)]
 enum Commands {
+    /// Lint Kubernetes manifests
+    #[command(after_help = "Exit codes:\n  0  No findings at or above the --fail-on severity\n  1  Findings at or above the --fail-on severity\n  2  Invalid arguments or config, unreadable inputs, or failed writes")]
     Lint {
+        /// Manifest files, directories to scan recursively, or glob patterns
+        #[arg(required_unless_present_any = ["path", "input_list"])]
+        paths: Vec<String>,
+
+        /// Same as the positional paths; may be repeated
         #[arg(short, long)]
-        path: String,
+        path: Vec<String>,
+
+        /// Read more paths from this file, one per line (`-` for stdin)
+        #[arg(long)]
+        input_list: Option<String>,
+
+        /// How many directory levels to descend into (unlimited by default)
+        #[arg(long)]
+        max_depth: Option<usize>,
+
+        /// Skip files and directories matching this pattern (.rustykubeignore syntax); may be repeated
+        #[arg(long)]
+        exclude: Vec<String>,
 
+        /// Don't honor .gitignore or .rustykubeignore files
+        #[arg(long)]
+        no_ignore: bool,
+
+        /// Skip files larger than this, e.g. 512K or 10M; 0 disables the limit
+        #[arg(long, default_value = "10M", value_parser = parse_size)]
+        max_file_size: u64,
+
+        /// Memory-map files and skip those without kind and apiVersion keys (YAML or JSON) before parsing them
+        #[arg(long)]
+        prescan: bool,
+
+        /// Lint documents that don't look like Kubernetes resources instead of skipping them
+        #[arg(long = "include-non-k8s")]
+        include_non_k8s: bool,
+
+        /// Also print JSON results after the human-readable output
         #[arg(long)]
         json: bool,
+
+        /// Output format, optionally written to a file: human, json, sarif
+        /// or junit, e.g. sarif:out.sarif; may be repeated
+        #[arg(long = "format", value_name = "FORMAT")]
+        formats: Vec<Output>,
+
+        /// Print ASCII tags instead of emojis; overrides output.plain
+        #[arg(long)]
+        plain: bool,
+
+        /// Exit with status 1 when findings of this severity or worse are
+        /// reported: warning, error or critical
+        #[arg(long, default_value = "error", value_parser = parse_severity)]
+        fail_on: Severity,
+
+        /// Keep running and re-lint whenever a watched file changes
+        #[arg(long)]
+        watch: bool,
+
+        /// Only lint documents matching this filter, e.g. kind=Deployment,name=api
+        /// (keys: kind, name, namespace); may be repeated
+        #[arg(long = "target", value_name = "FILTER")]
+        targets: Vec<Target>,
+
+        /// Only run these rules (comma-separated rule ids)
+        #[arg(long, value_delimiter = ',')]
+        rules: Vec<String>,
+
+        /// Target Kubernetes version, e.g. 1.29; overrides cluster-profile.kubernetes-version
+        #[arg(long)]
+        kubernetes_version: Option<String>,
+
+        /// Config file (defaults to .rustykube.yaml when present)
+        #[arg(long)]
+        config: Option<String>,
+    },
+
+    /// Time the rule engine on synthetic fixtures
+    #[command(hide = true)]
+    Bench {
+        /// Fixture size: small, medium or huge
+        #[arg(long, default_value = "medium")]
+        size: String,
+
+        #[arg(long, default_value_t = 5)]
+        iterations: u32,
+
+        /// Exit non-zero when linting all rules takes longer than this many
+        /// milliseconds per document, to catch performance regressions in CI
+        #[arg(long)]
+        max_ms_per_doc: Option<f64>,
     },
 }
 
@@ -27,6 +127,65 @@ fn main() {
     let cli = Cli::parse();
 
     match &cli.command {
-        Commands::Lint { path, json } => commands::lint::run_lint(path, *json),
+        Commands::Lint { paths, path, input_list, max_depth, exclude, no_ignore, max_file_size, prescan, include_non_k8s, json, formats, plain, fail_on, watch, targets, rules, kubernetes_version, config: config_path } => {
+            let style = Style::new(*plain);
+            if let Some(version) = kubernetes_version {
+                if utils::parse_kubernetes_version(version).is_none() {
+                    eprintln!("{} Invalid Kubernetes version: {}", style.error(), version);
+                    process::exit(EXIT_ERROR);
+                }
+            }
+            // Reads everything a watch run picks up again when it changes.
+            let load = || -> rustykube_core::Result<Inputs> {
+                let mut config = Config::load(config_path.as_deref())?;
+                config.output.plain |= *plain;
+                let known: Vec<&str> = lint_rules::default_rules(&config).iter().map(|rule| rule.id()).collect();
+                config::check_ids(rules, &known, || "--rules".to_string())?;
+                config.check_rule_ids(&known)?;
+                if let Some(version) = kubernetes_version {
+                    config.cluster_profile.kubernetes_version = Some(version.clone());
+                }
+                let mut paths: Vec<String> = path.iter().chain(paths).cloned().collect();
+                if let Some(list) = input_list {
+                    paths.extend(discovery::read_input_list(list)?);
+                }
+                Ok(Inputs { paths, config })
+            };
+            let inputs = load().unwrap_or_else(|err| {
+                eprintln!("{} {}", style.error(), err);
+                process::exit(EXIT_ERROR);
+            });
+            let watched_files = watch.then(|| {
+                let config_file = config_path.as_deref().unwrap_or(config::DEFAULT_CONFIG_FILE);
+                let list = input_list.as_deref().filter(|list| *list != "-");
+                [config_file].into_iter().chain(list).map(PathBuf::from).collect()
+            });
+
+            let discovery = Discovery { max_depth: *max_depth, exclude: exclude.clone(), gitignore: !*no_ignore };
+            let files = FileOptions {
+                max_file_size: (*max_file_size > 0).then_some(*max_file_size),
+                prescan: *prescan,
+                include_non_kubernetes: *include_non_k8s,
+                targets: targets.clone(),
+            };
+            let mut outputs = formats.clone();
+            if outputs.is_empty() {
+                outputs.push(Output::stdout(Format::Human));
+            }
+            if *json {
+                outputs.push(Output::stdout(Format::Json));
+            }
+            let report = ReportOptions { outputs, fail_on: *fail_on };
+            commands::lint::run_lint(inputs, &load, discovery, files, report, watched_files, rules)
+        }
+        Commands::Bench { size, iterations, max_ms_per_doc } => commands::bench::run_bench(size, *iterations, *max_ms_per_doc),
     }
 }
+
+fn parse_severity(severity: &str) -> Result<Severity, String> {
+    Severity::parse(severity).ok_or_else(|| format!("unknown severity '{}'; expected warning, error or critical", severity))
+}
+
+fn parse_size(size: &str) -> Result<u64, String> {
+    utils::parse_size(size).ok_or_else(|| format!("invalid size '{}'", size))
+}
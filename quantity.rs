@@ -0,0 +1,174 @@
+//! Kubernetes resource quantities (`500m`, `1.5`, `128Mi`, `2G`, `1e3`).
+//!
+//! Quantities are held exactly as a count of nano-units, so `1Gi` equals
+//! `1024Mi` and `500m` orders below `1` without string or float tricks.
+//! Precision finer than a nano-unit is rounded up, as Kubernetes does.
+
+use serde_yaml::Value;
+use std::fmt;
+
+const NANOS: i128 = 1_000_000_000;
+
+const BINARY_SUFFIXES: &[(&str, u32)] = &[("Ki", 1), ("Mi", 2), ("Gi", 3), ("Ti", 4), ("Pi", 5), ("Ei", 6)];
+const DECIMAL_SUFFIXES: &[(&str, i32)] = &[("n", -9), ("u", -6), ("m", -3), ("k", 3), ("M", 6), ("G", 9), ("T", 12), ("P", 15), ("E", 18)];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Quantity {
+    nanos: i128,
+}
+
+impl Quantity {
+    /// Parses a quantity string, returning `None` if it is malformed or too
+    /// large to represent.
+    pub fn parse(quantity: &str) -> Option<Quantity> {
+        let quantity = quantity.trim();
+        let end = quantity.find(|c: char| !(c.is_ascii_digit() || matches!(c, '.' | '+' | '-'))).unwrap_or(quantity.len());
+        let (number, suffix) = quantity.split_at(end);
+
+        let (negative, number) = match number.strip_prefix('-') {
+            Some(number) => (true, number),
+            None => (false, number.strip_prefix('+').unwrap_or(number)),
+        };
+        let (whole, fraction) = number.split_once('.').unwrap_or((number, ""));
+        if whole.is_empty() && fraction.is_empty() || !(whole.chars().chain(fraction.chars()).all(|c| c.is_ascii_digit())) {
+            return None;
+        }
+        let mantissa: i128 = format!("{}{}", whole, fraction).parse().ok()?;
+
+        // Value is mantissa * 2^(10 * binary) * 10^(exponent - fraction digits), in units.
+        let (binary, exponent) = match suffix {
+            "" => (0, 0),
+            _ => match BINARY_SUFFIXES.iter().find(|(s, _)| *s == suffix) {
+                Some((_, power)) => (*power, 0),
+                None => match DECIMAL_SUFFIXES.iter().find(|(s, _)| *s == suffix) {
+                    Some((_, exponent)) => (0, *exponent),
+                    None => (0, suffix.strip_prefix(['e', 'E'])?.parse::<i32>().ok()?),
+                },
+            },
+        };
+        let exponent = exponent + 9 - i32::try_from(fraction.len()).ok()?;
+
+        let scaled = mantissa.checked_mul(1i128.checked_shl(10 * binary)?)?;
+        let nanos = if exponent >= 0 {
+            scaled.checked_mul(10i128.checked_pow(exponent.unsigned_abs())?)?
+        } else {
+            let divisor = 10i128.checked_pow(exponent.unsigned_abs())?;
+            (scaled + divisor - 1) / divisor
+        };
+        Some(Quantity { nanos: if negative { -nanos } else { nanos } })
+    }
+
+    /// Reads a quantity from YAML, where plain numbers such as `cpu: 2` are
+    /// not strings.
+    pub fn from_value(value: &Value) -> Option<Quantity> {
+        match value {
+            Value::String(quantity) => Quantity::parse(quantity),
+            Value::Number(number) => Quantity::parse(&number.to_string()),
+            _ => None,
+        }
+    }
+
+    /// Value in base units (cores, bytes), rounded to the nearest `f64`.
+    pub fn as_f64(&self) -> f64 {
+        self.nanos as f64 / NANOS as f64
+    }
+
+    /// Value in thousandths of a base unit, e.g. millicores, rounded up.
+    pub fn as_millis(&self) -> i128 {
+        (self.nanos + 999_999).div_euclid(1_000_000)
+    }
+}
+
+/// Normalized form: the largest binary suffix for whole multiples of 1Ki,
+/// otherwise the largest decimal suffix that keeps the value whole.
+impl fmt::Display for Quantity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.nanos == 0 {
+            return write!(f, "0");
+        }
+        for (suffix, power) in BINARY_SUFFIXES.iter().rev() {
+            let unit = NANOS << (10 * power);
+            if self.nanos % unit == 0 {
+                return write!(f, "{}{}", self.nanos / unit, suffix);
+            }
+        }
+        let decimal = DECIMAL_SUFFIXES.iter().map(|(suffix, exponent)| (*suffix, *exponent)).chain([("", 0)]);
+        let mut decimal: Vec<_> = decimal.collect();
+        decimal.sort_by_key(|(_, exponent)| -exponent);
+        for (suffix, exponent) in decimal {
+            let unit = 10i128.pow((exponent + 9) as u32);
+            if self.nanos % unit == 0 {
+                return write!(f, "{}{}", self.nanos / unit, suffix);
+            }
+        }
+        unreachable!("every quantity is a whole number of nano-units")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Quantity;
+
+    fn q(quantity: &str) -> Quantity {
+        Quantity::parse(quantity).unwrap_or_else(|| panic!("{} should parse", quantity))
+    }
+
+    #[test]
+    fn binary_and_decimal_suffixes_compare_exactly() {
+        assert_eq!(q("1Gi"), q("1024Mi"));
+        assert_eq!(q("1Ki"), q("1024"));
+        assert_eq!(q("1k"), q("1000"));
+        assert_eq!(q("1M"), q("1000k"));
+        assert_ne!(q("1G"), q("1Gi"));
+        assert!(q("1G") < q("1Gi"));
+    }
+
+    #[test]
+    fn milli_units_order_below_whole_units() {
+        assert!(q("500m") < q("1"));
+        assert_eq!(q("1000m"), q("1"));
+        assert_eq!(q("0.5"), q("500m"));
+        assert_eq!(q("500m").as_millis(), 500);
+        assert_eq!(q("2").as_millis(), 2000);
+    }
+
+    #[test]
+    fn exponent_and_decimal_forms() {
+        assert_eq!(q("1e3"), q("1k"));
+        assert_eq!(q("1E3"), q("1k"));
+        assert_eq!(q("1e-3"), q("1m"));
+        assert_eq!(q("1.5Gi"), q("1536Mi"));
+        assert_eq!(q(".5"), q("500m"));
+        assert_eq!(q("+2"), q("2"));
+        assert!(q("-1") < q("0"));
+        assert_eq!(q("1.5").as_f64(), 1.5);
+    }
+
+    #[test]
+    fn sub_nano_precision_rounds_up() {
+        assert_eq!(q("0.1n"), q("1n"));
+        assert_eq!(q("1.0000000001"), q("1000000001n"));
+    }
+
+    #[test]
+    fn rejects_malformed_quantities() {
+        for quantity in ["", "abc", "1X", "Mi", ".", "1.2.3", "1 Gi", "1e", "1ee3", "--1"] {
+            assert_eq!(Quantity::parse(quantity), None, "{:?} should not parse", quantity);
+        }
+    }
+
+    #[test]
+    fn rejects_overflow() {
+        assert_eq!(Quantity::parse("1e100"), None);
+        assert_eq!(Quantity::parse("1000000000000000000000000000000000000000000"), None);
+        assert_eq!(Quantity::parse("100000000000000000000Ei"), None);
+    }
+
+    #[test]
+    fn display_normalizes_and_round_trips() {
+        for (quantity, normalized) in [("1024Mi", "1Gi"), ("1536Mi", "1536Mi"), ("1000", "1k"), ("1500m", "1500m"), ("0", "0"), ("0.5", "500m"), ("2048", "2Ki")] {
+            assert_eq!(q(quantity).to_string(), normalized);
+            assert_eq!(q(normalized), q(quantity));
+        }
+    }
+}
@@ -0,0 +1,29 @@
+use crate::linter::{Finding, ResourceReport, Skipped};
+
+/// Receives lint results as they are produced, so embedders can stream them
+/// into their own sinks instead of waiting for the final `LintReport`. All
+/// methods default to doing nothing.
+pub trait Reporter {
+    /// Called before the documents of `path` are linted.
+    fn file_started(&mut self, _path: &str) {}
+
+    /// Called for each finding as soon as its rule produces it.
+    fn finding(&mut self, _resource: &ResourceRef<'_>, _finding: &Finding) {}
+
+    /// Called once every rule has run against a resource.
+    fn resource_finished(&mut self, _path: &str, _resource: &ResourceReport) {}
+
+    fn skipped(&mut self, _skipped: &Skipped) {}
+}
+
+/// The resource a streamed finding belongs to.
+#[derive(Debug, Clone, Copy)]
+pub struct ResourceRef<'a> {
+    pub path: &'a str,
+    pub index: usize,
+    pub kind: &'a str,
+    pub name: &'a str,
+}
+
+/// Discards everything; used by the non-streaming `Linter` methods.
+impl Reporter for () {}
@@ -0,0 +1,202 @@
+//! Maps rule field paths back to positions in the original YAML text.
+//!
+//! `serde_yaml::Value` carries no span information, so locations are
+//! recovered from the block-style layout of the source: documents are split
+//! on `---` markers and field paths are resolved by indentation. Paths that
+//! cannot be followed (flow style, missing fields) resolve to the deepest
+//! ancestor that was found.
+
+/// 1-based position in a source file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Location {
+    pub line: usize,
+    pub column: usize,
+}
+
+/// Content-bearing line of a document, with the column where its content
+/// starts (after indentation, and after `- ` for the first line of an item).
+#[derive(Debug, Clone, Copy)]
+struct Entry<'a> {
+    line: usize,
+    column: usize,
+    text: &'a str,
+}
+
+enum Segment {
+    Key(String),
+    Index(usize),
+}
+
+/// Source text split into the same documents `serde_yaml` produces.
+pub struct SourceMap<'a> {
+    documents: Vec<Vec<Entry<'a>>>,
+    starts: Vec<usize>,
+}
+
+impl<'a> SourceMap<'a> {
+    pub fn new(contents: &'a str) -> Self {
+        let mut documents = vec![vec![]];
+        let mut starts = vec![1];
+        let mut saw_separator = false;
+
+        for (i, raw) in contents.lines().enumerate() {
+            let line = i + 1;
+            if is_document_separator(raw) {
+                // Comments ahead of the first `---` do not form a document.
+                if !saw_separator && documents[0].is_empty() {
+                    starts[0] = line + 1;
+                } else {
+                    documents.push(vec![]);
+                    starts.push(line + 1);
+                }
+                saw_separator = true;
+                continue;
+            }
+
+            let text = raw.trim_start_matches(' ');
+            if text.is_empty() || text.starts_with('#') || raw.starts_with("...") {
+                continue;
+            }
+            let column = raw.len() - text.len();
+            documents.last_mut().unwrap().push(Entry { line, column, text });
+        }
+
+        SourceMap { documents, starts }
+    }
+
+    /// Line on which document `index` starts.
+    pub fn document_start(&self, index: usize) -> Option<usize> {
+        self.starts.get(index).copied()
+    }
+
+    /// Best-effort location of `field` within document `index`.
+    pub fn locate(&self, index: usize, field: &str) -> Option<Location> {
+        let mut entries: &[Entry] = self.documents.get(index)?;
+        let mut owned;
+        let mut found = entries.first().map(|e| Location { line: e.line, column: e.column + 1 });
+
+        for segment in parse_field_path(field) {
+            let next = match segment {
+                Segment::Key(key) => find_key(entries, &key),
+                Segment::Index(n) => find_item(entries, n),
+            };
+            let Some((location, children)) = next else { break };
+            found = Some(location);
+            owned = children;
+            entries = &owned;
+        }
+        found
+    }
+}
+
+fn is_document_separator(line: &str) -> bool {
+    line == "---" || line.starts_with("--- ") || line.starts_with("---\t")
+}
+
+/// Finds `key` among the mapping entries at the first entry's column and
+/// returns its location plus the entries making up its value.
+fn find_key<'a>(entries: &[Entry<'a>], key: &str) -> Option<(Location, Vec<Entry<'a>>)> {
+    let column = entries.first()?.column;
+    let position = entries
+        .iter()
+        .position(|e| e.column == column && entry_key(e.text) == Some(key))?;
+    let entry = entries[position];
+
+    let mut children = vec![];
+    for e in &entries[position + 1..] {
+        if e.column < column || (e.column == column && !is_item(e.text)) {
+            break;
+        }
+        children.push(*e);
+    }
+    Some((Location { line: entry.line, column: entry.column + 1 }, children))
+}
+
+/// Finds the `n`th sequence item and returns its location plus the entries
+/// of the item, with the first line re-based past the `- ` marker.
+fn find_item<'a>(entries: &[Entry<'a>], n: usize) -> Option<(Location, Vec<Entry<'a>>)> {
+    let column = entries.first()?.column;
+    let position = entries
+        .iter()
+        .enumerate()
+        .filter(|(_, e)| e.column == column && is_item(e.text))
+        .nth(n)
+        .map(|(i, _)| i)?;
+    let entry = entries[position];
+
+    let mut children = vec![];
+    let rest = entry.text[1..].trim_start();
+    if !rest.is_empty() {
+        children.push(Entry { line: entry.line, column: entry.column + entry.text.len() - rest.len(), text: rest });
+    }
+    for e in &entries[position + 1..] {
+        if e.column <= column {
+            break;
+        }
+        children.push(*e);
+    }
+    Some((Location { line: entry.line, column: entry.column + 1 }, children))
+}
+
+fn is_item(text: &str) -> bool {
+    text == "-" || text.starts_with("- ")
+}
+
+/// Unquoted key of a `key: value` line.
+fn entry_key(text: &str) -> Option<&str> {
+    let raw = &text[..key_length(text)];
+    if raw.len() == text.len() {
+        return None;
+    }
+    Some(raw.trim_matches(|c| c == '"' || c == '\''))
+}
+
+fn key_length(text: &str) -> usize {
+    let bytes = text.as_bytes();
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b':' && (i + 1 == bytes.len() || bytes[i + 1] == b' ') {
+            return i;
+        }
+        i += 1;
+    }
+    text.len()
+}
+
+/// Parses `spec.containers[0].env` and `metadata.labels["app.kubernetes.io/name"]`.
+fn parse_field_path(field: &str) -> Vec<Segment> {
+    let mut segments = vec![];
+    let mut chars = field.chars();
+    let mut key = String::new();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '.' => {
+                if !key.is_empty() {
+                    segments.push(Segment::Key(std::mem::take(&mut key)));
+                }
+            }
+            '[' => {
+                if !key.is_empty() {
+                    segments.push(Segment::Key(std::mem::take(&mut key)));
+                }
+                let mut inner = String::new();
+                for c in chars.by_ref() {
+                    if c == ']' {
+                        break;
+                    }
+                    inner.push(c);
+                }
+                match inner.parse() {
+                    Ok(index) => segments.push(Segment::Index(index)),
+                    Err(_) => segments.push(Segment::Key(inner.trim_matches('"').to_string())),
+                }
+            }
+            _ => key.push(c),
+        }
+    }
+    if !key.is_empty() {
+        segments.push(Segment::Key(key));
+    }
+    segments
+}
@@ -1,8 +1,87 @@
 use serde_yaml::{Deserializer, Value};
 use serde::de::Deserialize;
 
-pub fn parse_yaml(contents: &str) -> Vec<Value> {
+pub fn parse_yaml(contents: &str) -> serde_yaml::Result<Vec<Value>> {
     Deserializer::from_str(contents)
-        .map(|doc| Value::deserialize(doc).expect("Failed to deserialize YAML document"))
+        .map(Value::deserialize)
         .collect()
 }
+
+/// Parses documents up to the first error, returning what was parsed along
+/// with the error that stopped parsing, if any. Takes bytes so memory-mapped
+/// files parse in place.
+pub fn parse_documents(contents: &[u8]) -> (Vec<Value>, Option<serde_yaml::Error>) {
+    let mut docs = vec![];
+    for doc in Deserializer::from_slice(contents) {
+        match Value::deserialize(doc) {
+            Ok(value) => docs.push(value),
+            Err(err) => return (docs, Some(err)),
+        }
+    }
+    (docs, None)
+}
+
+/// Heuristic for unrendered Helm/Go templates in a file that does not
+/// parse as YAML: any `{{` action. Files that parse are linted whatever
+/// their values hold, so leaked `{{ .Values.x }}` actions get reported and
+/// Go templates in alerting rules are not mistaken for unrendered files.
+pub fn is_templated(contents: &str) -> bool {
+    contents.contains("{{")
+}
+
+/// Parses a byte size such as `512`, `64K`, `10MB` or `1GiB`. Suffixes are
+/// binary multiples and case-insensitive.
+pub fn parse_size(size: &str) -> Option<u64> {
+    let size = size.trim();
+    let digits = size.find(|c: char| !c.is_ascii_digit()).unwrap_or(size.len());
+    let (number, unit) = size.split_at(digits);
+    let multiplier: u64 = match unit.trim().to_ascii_uppercase().as_str() {
+        "" | "B" => 1,
+        "K" | "KB" | "KIB" => 1 << 10,
+        "M" | "MB" | "MIB" => 1 << 20,
+        "G" | "GB" | "GIB" => 1 << 30,
+        _ => return None,
+    };
+    number.parse::<u64>().ok()?.checked_mul(multiplier)
+}
+
+/// Parses a Kubernetes version such as `1.29`, `v1.29.3` or `1.30.0-eks`
+/// into `(major, minor)`.
+pub fn parse_kubernetes_version(version: &str) -> Option<(u32, u32)> {
+    let mut parts = version.trim().trim_start_matches('v').split(['.', '-', '+']);
+    let major = parts.next()?.parse().ok()?;
+    let minor = parts.next()?.parse().ok()?;
+    Some((major, minor))
+}
+
+/// Matches a `/`-separated path against a glob pattern. `*` and `?` match
+/// within a single path segment, `**` matches any number of segments.
+pub fn glob_match(pattern: &str, path: &str) -> bool {
+    let pattern: Vec<&str> = pattern.trim_start_matches("./").split('/').collect();
+    let path: Vec<&str> = path.trim_start_matches("./").split('/').collect();
+    match_segments(&pattern, &path)
+}
+
+fn match_segments(pattern: &[&str], path: &[&str]) -> bool {
+    match pattern.split_first() {
+        None => path.is_empty(),
+        Some((&"**", rest)) => (0..=path.len()).any(|skip| match_segments(rest, &path[skip..])),
+        Some((segment, rest)) => match path.split_first() {
+            Some((name, path_rest)) => {
+                let segment: Vec<char> = segment.chars().collect();
+                let name: Vec<char> = name.chars().collect();
+                match_segment(&segment, &name) && match_segments(rest, path_rest)
+            }
+            None => false,
+        },
+    }
+}
+
+fn match_segment(pattern: &[char], name: &[char]) -> bool {
+    match pattern.split_first() {
+        None => name.is_empty(),
+        Some(('*', rest)) => (0..=name.len()).any(|skip| match_segment(rest, &name[skip..])),
+        Some(('?', rest)) => !name.is_empty() && match_segment(rest, &name[1..]),
+        Some((c, rest)) => name.first() == Some(c) && match_segment(rest, &name[1..]),
+    }
+}